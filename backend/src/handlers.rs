@@ -1,5 +1,43 @@
-use axum::response::Html;
+use axum::{
+    extract::Query,
+    http::{StatusCode, header},
+    response::{Html, IntoResponse, Response},
+};
+use picker_util::{
+    clock::{Clock, SystemClock},
+    holiday::HolidayCalendar,
+    ical::Calendar,
+};
+use serde_derive::Deserialize;
 
 pub async fn greet_handler() -> Html<&'static str> {
     Html("<h1>Nice to meet you!</h1>")
 }
+
+#[derive(Deserialize)]
+pub struct YearQuery {
+    /// Common era year, the current year if missing
+    year: Option<i32>,
+}
+
+/// Thai public holidays as iCalendar feed, ex. `/api/holidays.ics?year=2025`<br>
+/// year out of `HolidayCalendar::lunar_years()` is `400 Bad Request`, its lunar holidays are unknown
+pub async fn holidays_ics_handler(Query(query): Query<YearQuery>) -> Response {
+    let year = query.year.unwrap_or_else(|| SystemClock.today().year());
+    let calendar = HolidayCalendar::new();
+    if let Some(years) = calendar.lunar_years()
+        && !years.contains(&year)
+    {
+        return (
+            StatusCode::BAD_REQUEST,
+            format!("year must be {} to {}", years.start(), years.end()),
+        )
+            .into_response();
+    }
+    let holidays = calendar.holidays_in_year(year);
+    (
+        [(header::CONTENT_TYPE, "text/calendar; charset=utf-8")],
+        Calendar::from_holidays(&holidays).to_string(),
+    )
+        .into_response()
+}
@@ -33,6 +33,7 @@ pub fn router() -> Router {
 
     Router::new()
         .route("/greet", get(handlers::greet_handler))
+        .route("/holidays.ics", get(handlers::holidays_ics_handler))
         // .with_state(state)
         .layer(TimeoutLayer::new(Duration::from_secs(30)))
         .layer(CookieManagerLayer::new())
@@ -1,6 +1,9 @@
 use dominator::{Dom, DomBuilder, EventOptions, clone, events, html, with_node};
-use futures_signals::signal::{Broadcaster, Mutable, Signal, SignalExt, not};
-use time::PrimitiveDateTime;
+use futures_signals::{
+    map_ref,
+    signal::{Broadcaster, Mutable, Signal, SignalExt, not},
+};
+use time::{PrimitiveDateTime, macros::datetime};
 use time_datepicker_core::config::{
     PickerConfig,
     date_constraints::{DateConstraints, HasDateConstraints},
@@ -8,12 +11,21 @@ use time_datepicker_core::config::{
 use web_sys::{HtmlElement, HtmlInputElement, window};
 
 use picker_util::{
-    JsTime, class, date_8601, date_from_pat, date_pat, date_str_th, datetime_8601,
-    datetime_from_pat, datetime_pat, datetime_str_th, js_now, time_8601, time_from_pat, time_pat,
-    time_str_hm,
+    JsTime, ParseError, Precision, class, date_8601, datetime_8601,
+    locale::Locale,
+    pattern::Pattern,
+    reading::{
+        Reading, try_date_readings_from_pat_pivot, try_datetime_readings_from_pat_pivot,
+        try_time_readings_from_pat_with,
+    },
+    relative::{date_from_relative, datetime_from_relative, time_from_relative},
+    time_8601,
 };
 
-use crate::{doms, picker::DatePicker};
+use crate::{
+    doms,
+    picker::{DatePicker, PickerOptions},
+};
 
 #[derive(Clone)]
 pub enum Picker {
@@ -22,6 +34,47 @@ pub enum Picker {
     Time,
 }
 
+impl Picker {
+    /// `DD/MM/BBBB HH:mm` of input text, `DD/MM/YYYY HH:mm` in Common era locale
+    fn input_pattern(&self, locale: &dyn Locale, precision: Precision) -> String {
+        let date = if locale.era_offset() == 543 {
+            "DD/MM/BBBB"
+        } else {
+            "DD/MM/YYYY"
+        };
+        match self {
+            Picker::DateTime => [date, " ", precision.time_pattern()].concat(),
+            Picker::Date => String::from(date),
+            Picker::Time => String::from(precision.time_pattern()),
+        }
+    }
+
+    /// `D MMMBBBB HH:mm น.` of overlay label
+    fn label_pattern(&self, locale: &dyn Locale, precision: Precision) -> String {
+        let time = [precision.time_pattern(), "[", locale.time_suffix(), "]"].concat();
+        match self {
+            Picker::DateTime => [locale.date_pattern(), " ", &time].concat(),
+            Picker::Date => String::from(locale.date_pattern()),
+            Picker::Time => time,
+        }
+    }
+
+    /// format ISO-8601 value of this picker, `2024-01-30T12:34`, `2024-01-30` or `12:34`
+    fn format(&self, pattern: &Pattern, value: &str) -> String {
+        match self {
+            Picker::DateTime => datetime_8601(value).map(|dt| pattern.format_datetime(&dt)),
+            Picker::Date => date_8601(value).map(|d| pattern.format_date(&d)),
+            Picker::Time => time_8601(value).map(|t| pattern.format_time(&t)),
+        }
+        .unwrap_or_default()
+    }
+}
+
+/// values of readings, best first
+fn values<T>(readings: Vec<Reading<T>>) -> Vec<T> {
+    readings.into_iter().map(|reading| reading.value).collect()
+}
+
 /// paired_mutable will use to constrain Date or Time mode<br>
 /// Date mode paired with Time<br>
 /// Time mode paired with Date
@@ -36,6 +89,7 @@ pub fn datetime_input_with_picker<B, C, D, F, S, T>(
     input_mixin: D,
     update_fn: F,
     config_signal: T,
+    options: PickerOptions,
 ) -> Dom
 where
     B: FnOnce(DomBuilder<HtmlElement>) -> DomBuilder<HtmlElement>,
@@ -46,10 +100,24 @@ where
     T: Signal<Item = Option<PickerConfig<DateConstraints>>> + 'static,
 {
     let date_active = Mutable::new(false);
+    let error_mutable: Mutable<Option<String>> = Mutable::new(None);
+    // invalid typed text, shown in input while the value is cleared
+    let typed_mutable: Mutable<Option<String>> = Mutable::new(None);
+    let choices_mutable: Mutable<Vec<String>> = Mutable::new(Vec::new());
     let picker_mutable = Mutable::new(None);
-    let now = js_now();
     let disable_broadcast = Broadcaster::new(disable_signal);
     let config_broadcast = Broadcaster::new(config_signal);
+    let locale = options.locale;
+    let precision = options.precision;
+    let input_pattern = options
+        .input_pattern
+        .clone()
+        .unwrap_or_else(|| Pattern::new(&picker.input_pattern(locale, precision)));
+    let label_pattern = options.label_pattern.clone().unwrap_or_else(|| {
+        Pattern::new(&picker.label_pattern(locale, precision)).with_locale(locale)
+    });
+    // input pattern example, longer names may be typed
+    let example = input_pattern.format_datetime(&datetime!(2025-08-31 23:45:30.5));
 
     html!("div", {
         .class("position-relative")
@@ -58,43 +126,42 @@ where
         // overlay label element
         .child(html!("div", {
             .class("form-control")
+            .class_signal("is-invalid", error_mutable.signal_ref(Option::is_some))
             .style_signal("background-color", disable_broadcast.signal().map(|is_disable| if is_disable {"var(--bs-secondary-bg)"} else {"var(--bs-body-bg)"}))
             .apply(label_mixin)
             .style("pointer-events", "none")
             .style("position", "absolute")
             .style("height", "100%")
-            .style_signal("z-index", date_active.signal().map(|is_active| if is_active {"-1"} else {"1"}))
-            .text_signal(date_mutable.clone().signal_cloned().map(clone!(picker => move |s| {
-                match picker {
-                    Picker::DateTime => datetime_str_th(&s),
-                    Picker::Date => date_str_th(&s),
-                    Picker::Time => time_str_hm(&s),
-                }
-            })))
+            // invalid text stays visible under its feedback
+            .style_signal("z-index", map_ref!{
+                let is_active = date_active.signal(),
+                let is_invalid = error_mutable.signal_ref(Option::is_some) =>
+                if *is_active || *is_invalid {"-1"} else {"1"}
+            })
+            .text_signal(date_mutable.clone().signal_cloned().map(clone!(picker, label_pattern => move |s| picker.format(&label_pattern, &s))))
         }))
         // input element
-        .child_signal(config_broadcast.signal_cloned().map(clone!(date_mutable, changed_mutable, paired_mutable, picker, disable_broadcast, update_fn => move |config| {
+        .child_signal(config_broadcast.signal_cloned().map(clone!(date_mutable, changed_mutable, paired_mutable, picker, disable_broadcast, update_fn, error_mutable, typed_mutable, choices_mutable, options, input_pattern => move |config| {
             Some(html!("input" => HtmlInputElement, {
                 .attr("type", "text")
                 .class("form-control")
+                .class_signal("is-invalid", error_mutable.signal_ref(Option::is_some))
                 .apply(input_mixin.clone())
-                .attr("placeholder", match picker {
-                    Picker::DateTime => "เช่น 31/8/68 23:45",
-                    Picker::Date => "เช่น 31/8/68",
-                    Picker::Time => "เช่น 23:45",
-                })
-                .attr("maxlength", match picker {
-                    Picker::DateTime => "16",
-                    Picker::Date => "10",
-                    Picker::Time => "5",
-                })
-                .prop_signal("value", date_mutable.signal_cloned().map(clone!(picker => move |s| {
-                    match picker {
-                        Picker::DateTime => datetime_8601(&s).map(|dt| datetime_pat(&dt)).unwrap_or_default(),
-                        Picker::Date => date_8601(&s).map(|d| date_pat(&d)).unwrap_or_default(),
-                        Picker::Time => time_8601(&s).map(|t| time_pat(&t)).unwrap_or_default(),
+                .attr("placeholder", &[locale.example(), " ", &example].concat())
+                // long enough for relative phrase, ex. `สิ้นเดือนหน้า 14:00`
+                .attr("maxlength", &match picker {
+                    Picker::DateTime => 19 + precision.time_len(),
+                    Picker::Date => 20,
+                    Picker::Time => precision.time_len().max(20),
+                }.max(example.chars().count() + 10).to_string())
+                .prop_signal("value", {
+                    let (picker, input_pattern) = (picker.clone(), input_pattern.clone());
+                    map_ref!{
+                        let date = date_mutable.signal_cloned(),
+                        let typed = typed_mutable.signal_cloned() =>
+                        typed.clone().unwrap_or_else(|| picker.format(&input_pattern, date))
                     }
-                })))
+                })
                 // set overlay label's z-index to 1 when input is blur
                 .event(clone!(date_active => move |_:events::Blur| {
                     date_active.set(false);
@@ -118,41 +185,97 @@ where
                         }
                     }))
                     // on change event
-                    .event(clone!(date_mutable, changed_mutable, paired_mutable, picker, update_fn => move |_:events::Change| {
+                    .event(clone!(date_mutable, changed_mutable, paired_mutable, picker, update_fn, error_mutable, typed_mutable, choices_mutable, options, input_pattern => move |_:events::Change| {
                         let v = element.value();
-                        let iso = match picker {
-                            Picker::DateTime => datetime_from_pat(&v).map(clone!(config => move |dt| {
-                                if config.is_none() {
-                                    dt.js_string()
-                                } else if let Some(c) = config && !c.is_datetime_forbidden(&dt) {
-                                    dt.js_string()
+                        // relative phrase first, ex. `พรุ่งนี้`, `อีก 3 วัน`, `อีก 30 นาที`
+                        let now = options.now();
+                        let custom = options.input_pattern.as_ref();
+                        // pat parsers read Buddhist era only, Common era locale parses by its input pattern
+                        let custom_date = custom.or((locale.era_offset() != 543).then_some(&input_pattern));
+                        // forbidden holiday or not a business day
+                        let unavailable = |dt: &PrimitiveDateTime| options.is_day_unavailable(&dt.date());
+                        // every reading of ambiguous input, ex. `235` is `02:35` or `23:05`
+                        let parsed = match picker {
+                            Picker::DateTime => datetime_from_relative(&v, &now).map(|dt| Ok(vec![dt])).unwrap_or_else(|| match custom_date {
+                                Some(pattern) => pattern.try_parse_datetime_pivot(&v, options.year_pivot, &now.date()).map(|dt| vec![dt]),
+                                None => try_datetime_readings_from_pat_pivot(&v, precision, options.year_pivot, &now.date()).map(values),
+                            }).map(|readings| readings.into_iter().map(|dt| {
+                                if unavailable(&dt) {
+                                    String::new()
+                                } else if config.is_none() {
+                                    dt.js_string_with(precision)
+                                } else if let Some(c) = &config && !c.is_datetime_forbidden(&dt) {
+                                    dt.js_string_with(precision)
                                 } else {
                                     String::new()
                                 }
-                            })).unwrap_or_default(),
-                            Picker::Date => date_from_pat(&v).map(clone!(paired_mutable, config => |d| {
-                                let new_time = paired_mutable.and_then(|time_paired| time_8601(&time_paired.get_cloned())).unwrap_or(now.time());
+                            }).collect::<Vec<_>>()),
+                            Picker::Date => date_from_relative(&v, &now).map(|d| Ok(vec![d])).unwrap_or_else(|| match custom_date {
+                                Some(pattern) => pattern.try_parse_date_pivot(&v, options.year_pivot, &now.date()).map(|d| vec![d]),
+                                None => try_date_readings_from_pat_pivot(&v, options.year_pivot, &now.date()).map(values),
+                            }).map(|readings| readings.into_iter().map(|d| {
+                                let new_time = paired_mutable.as_ref().and_then(|time_paired| time_8601(&time_paired.get_cloned())).unwrap_or(now.time());
                                 let new_datetime = PrimitiveDateTime::new(d, new_time);
-                                if config.is_none() {
+                                if unavailable(&new_datetime) {
+                                    String::new()
+                                } else if config.is_none() {
                                     d.to_string()
-                                } else if let Some(c) = config && !c.is_day_forbidden(&new_datetime) {
+                                } else if let Some(c) = &config && !c.is_day_forbidden(&new_datetime) {
                                     d.to_string()
                                 } else {
                                     String::new()
                                 }
-                            })).unwrap_or_default(),
-                            Picker::Time => time_from_pat(&v).map(clone!(paired_mutable, config => |t| {
-                                let new_date = paired_mutable.and_then(|date_paired| date_8601(&date_paired.get_cloned())).unwrap_or(now.date());
+                            }).collect::<Vec<_>>()),
+                            // time phrase only, `พรุ่งนี้` is not a time
+                            Picker::Time => time_from_relative(&v, &now).map(|t| Ok(vec![t])).unwrap_or_else(|| match custom {
+                                Some(pattern) => pattern.try_parse_time(&v).map(|t| vec![t]),
+                                None => try_time_readings_from_pat_with(&v, precision).map(values),
+                            }).map(|readings| readings.into_iter().map(|t| {
+                                let new_date = paired_mutable.as_ref().and_then(|date_paired| date_8601(&date_paired.get_cloned())).unwrap_or(now.date());
                                 let new_datetime = PrimitiveDateTime::new(new_date, t);
-                                if config.is_none() {
-                                    t.js_string()
-                                } else if let Some(c) = config && !c.is_day_forbidden(&new_datetime) {
-                                    t.js_string()
+                                if unavailable(&new_datetime) {
+                                    String::new()
+                                } else if config.is_none() {
+                                    t.js_string_with(precision)
+                                } else if let Some(c) = &config && !c.is_day_forbidden(&new_datetime) {
+                                    t.js_string_with(precision)
                                 } else {
                                     String::new()
                                 }
-                            })).unwrap_or_default(),
+                            }).collect::<Vec<_>>()),
                         };
+                        // invalid text is kept for its feedback, the value is cleared
+                        let invalid = |message: String| {
+                            error_mutable.set_neq(Some(message));
+                            choices_mutable.set_neq(Vec::new());
+                            typed_mutable.set(Some(v.clone()));
+                            if !date_mutable.lock_ref().is_empty() {
+                                date_mutable.set(String::new());
+                                changed_mutable.set_neq(true);
+                            }
+                        };
+                        // empty input is clearing, not an error
+                        let mut isos = match parsed {
+                            Ok(isos) => isos,
+                            Err(ParseError::Empty) => Vec::new(),
+                            Err(e) => return invalid(locale.parse_error(&e)),
+                        };
+                        // forbidden readings are dropped, all of them is an error
+                        let found = !isos.is_empty();
+                        isos.retain(|iso| !iso.is_empty());
+                        if found && isos.is_empty() {
+                            return invalid(String::from(locale.unavailable()));
+                        }
+                        error_mutable.set_neq(None);
+                        // more than one valid reading, let user choose instead of guessing
+                        if isos.len() > 1 {
+                            typed_mutable.set(Some(v));
+                            choices_mutable.set(isos);
+                            return;
+                        }
+                        typed_mutable.set_neq(None);
+                        choices_mutable.set_neq(Vec::new());
+                        let iso = isos.pop().unwrap_or_default();
                         let value = if iso.is_empty() {
                             iso
                         } else {
@@ -166,6 +289,61 @@ where
                 })
             }))
         })))
+        // invalid feedback and chooser, clear when value is selected by picker
+        .future(date_mutable.signal_cloned().for_each(clone!(error_mutable, typed_mutable, choices_mutable => move |v| {
+            if !v.is_empty() {
+                error_mutable.set_neq(None);
+                typed_mutable.set_neq(None);
+                choices_mutable.set_neq(Vec::new());
+            }
+            async {}
+        })))
+        .child_signal(error_mutable.signal_cloned().map(|opt| {
+            opt.map(|message| {
+                html!("div", {
+                    .class("invalid-tooltip")
+                    .style("display", "block")
+                    .text(&message)
+                })
+            })
+        }))
+        // chooser of ambiguous input, best reading first
+        .child_signal(choices_mutable.signal_cloned().map(clone!(date_mutable, changed_mutable, choices_mutable, picker, update_fn, label_pattern => move |choices| {
+            (!choices.is_empty()).then(|| html!("div", {
+                .style("position", "absolute")
+                .style("top", "100%")
+                .style("z-index", "5")
+                .style("margin-top", ".1rem")
+                .style("padding", ".25rem")
+                .style("display", "flex")
+                .style("flex-wrap", "wrap")
+                .style("align-items", "center")
+                .style("gap", ".25rem")
+                .style("background-color", "var(--bs-body-bg)")
+                .style("border", "var(--bs-border-width) solid var(--bs-border-color)")
+                .style("border-radius", "var(--bs-border-radius)")
+                .style("box-shadow", "var(--bs-box-shadow-sm)")
+                .child(html!("small", {
+                    .text(locale.did_you_mean())
+                }))
+                .children(choices.into_iter().enumerate().map(|(i, iso)| {
+                    html!("button", {
+                        .attr("type", "button")
+                        .class(["btn", "btn-sm", if i == 0 {"btn-primary"} else {"btn-outline-primary"}])
+                        .text(&picker.format(&label_pattern, &iso))
+                        .event(clone!(date_mutable, changed_mutable, choices_mutable, update_fn => move |_:events::Click| {
+                            let value = update_fn(iso.clone());
+                            if value != date_mutable.get_cloned() {
+                                changed_mutable.set_neq(true);
+                            }
+                            // set even if unchanged, input still shows typed text
+                            date_mutable.set(value);
+                            choices_mutable.set_neq(Vec::new());
+                        }))
+                    })
+                }))
+            }))
+        })))
         // picker container
         .child_signal(config_broadcast.signal_cloned().map(move |config| {
             Some(html!("div", {
@@ -184,11 +362,11 @@ where
                     .style("color", "var(--bs-body-color)")
                     .style("z-index","2")
                     .attr("title", match picker {
-                        Picker::DateTime => "แสดงเครื่องมือเลือกวันที่และเวลา",
-                        Picker::Date => "แสดงเครื่องมือเลือกวันที่",
-                        Picker::Time => "แสดงเครื่องมือเลือกเวลา",
+                        Picker::DateTime => locale.show_datetime_picker(),
+                        Picker::Date => locale.show_date_picker(),
+                        Picker::Time => locale.show_time_picker(),
                     })
-                    .event(clone!(date_mutable, changed_mutable, paired_mutable, picker_mutable, picker, update_fn, config => move |_:events::Click| {
+                    .event(clone!(date_mutable, changed_mutable, paired_mutable, picker_mutable, picker, update_fn, config, options => move |_:events::Click| {
                         if picker_mutable.get_cloned().is_none() {
                             let new_picker = match picker {
                                 Picker::DateTime => DatePicker::new_datetime(
@@ -197,6 +375,7 @@ where
                                     picker_mutable.clone(),
                                     update_fn.clone(),
                                     config.clone().unwrap_or_default(),
+                                    options.clone(),
                                 ),
                                 Picker::Date => DatePicker::new_date(
                                     date_mutable.clone(),
@@ -205,6 +384,7 @@ where
                                     picker_mutable.clone(),
                                     update_fn.clone(),
                                     config.clone().unwrap_or_default(),
+                                    options.clone(),
                                 ),
                                 Picker::Time => DatePicker::new_time(
                                     date_mutable.clone(),
@@ -213,6 +393,7 @@ where
                                     picker_mutable.clone(),
                                     update_fn.clone(),
                                     config.clone().unwrap_or_default(),
+                                    options.clone(),
                                 ),
                             };
                             picker_mutable.set(Some(new_picker));
@@ -228,7 +409,7 @@ where
                             Picker::DateTime => 342.0,
                             Picker::Date => 254.0,
                             Picker::Time => 94.0,
-                        };
+                        } + if precision > Precision::Minutes && !matches!(picker, Picker::Date) { 40.0 } else { 0.0 };
                         opt.map(|picker| {
                             doms::under_box(
                                 element.parent_element().unwrap().get_bounding_client_rect(),
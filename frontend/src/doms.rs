@@ -13,11 +13,14 @@ use web_sys::{DomRect, HtmlElement, HtmlInputElement};
 
 use super::datetime_pickers;
 
+pub use super::picker::PickerOptions;
+
 /// `DateTime` input with picker from `Mutable<String>`, update `Mutable<bool>` at the end of input/exit<br>
 /// - `disable_signal`: ex. `futures_signal::signal::always(false)`
 /// - `container_mixin`: ex. `|dom| dom.style("min-width","190px")`, `NOTE`: sm is `175px`)
 /// - `label_mixin`: apply to label element, ex. `|dom| dom.class("form-control-sm")`
 /// - `input_mixin`: apply to input element, ex. `|dom| dom.class("form-control-sm")`
+/// - `options`: display options, ex. `PickerOptions::default()` for Thai locale
 ///
 /// `NOTE`: under `input-group` parent
 /// - use `d-flex` and `flex-grow-1` classes to `container_mixin`
@@ -31,6 +34,7 @@ pub fn datetime_picker<B, C, D, F, S, T>(
     input_mixin: D,
     update_fn: F,
     config_signal: T,
+    options: PickerOptions,
 ) -> Dom
 where
     B: FnOnce(DomBuilder<HtmlElement>) -> DomBuilder<HtmlElement>,
@@ -51,6 +55,7 @@ where
         input_mixin,
         update_fn,
         config_signal,
+        options,
     )
 }
 
@@ -60,6 +65,7 @@ where
 /// - `container_mixin`: ex. `|dom| dom.style("min-width","135px")`, `NOTE`: sm is `120px`)
 /// - `label_mixin`: apply to label element, ex. `|dom| dom.class("form-control-sm")`
 /// - `input_mixin`: apply to input element, ex. `|dom| dom.class("form-control-sm")`
+/// - `options`: display options, ex. `PickerOptions::default()` for Thai locale
 ///
 /// `NOTE`: under `input-group` parent
 /// - use `d-flex` and `flex-grow-1` classes to `container_mixin`
@@ -74,6 +80,7 @@ pub fn date_picker<B, C, D, F, S, T>(
     input_mixin: D,
     update_fn: F,
     config_signal: T,
+    options: PickerOptions,
 ) -> Dom
 where
     B: FnOnce(DomBuilder<HtmlElement>) -> DomBuilder<HtmlElement>,
@@ -94,6 +101,7 @@ where
         input_mixin,
         update_fn,
         config_signal,
+        options,
     )
 }
 
@@ -103,6 +111,7 @@ where
 /// - `container_mixin`: ex. `|dom| dom.style("min-width","110px")`, `NOTE`: sm is `95px`)
 /// - `label_mixin`: apply to label element, ex. `|dom| dom.class("form-control-sm")`
 /// - `input_mixin`: apply to input element, ex. `|dom| dom.class("form-control-sm")`
+/// - `options`: display options, ex. `PickerOptions::default()` for Thai locale
 ///
 /// `NOTE`: under `input-group` parent
 /// - use `d-flex` and `flex-grow-1` classes to `container_mixin`
@@ -117,6 +126,7 @@ pub fn time_picker<B, C, D, F, S, T>(
     input_mixin: D,
     update_fn: F,
     config_signal: T,
+    options: PickerOptions,
 ) -> Dom
 where
     B: FnOnce(DomBuilder<HtmlElement>) -> DomBuilder<HtmlElement>,
@@ -137,6 +147,7 @@ where
         input_mixin,
         update_fn,
         config_signal,
+        options,
     )
 }
 
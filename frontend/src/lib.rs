@@ -1,6 +1,7 @@
 use dominator::{Dom, clone, events, html, with_node};
 use futures_signals::signal::{Mutable, SignalExt, always, not};
-use time::{Date, Duration, PrimitiveDateTime, Time, Weekday};
+use std::rc::Rc;
+use time::{Date, Duration, PrimitiveDateTime, Time, UtcOffset, Weekday};
 use wasm_bindgen::prelude::*;
 use web_sys::HtmlInputElement;
 
@@ -8,7 +9,18 @@ mod datetime_pickers;
 mod doms;
 mod picker;
 
-use picker_util::{JsTime, class, date_8601, datetime_8601, datetime_th, js_now, time_8601};
+use picker_util::{
+    JsTime, Precision, YearPivot,
+    business::BusinessCalendar,
+    class, date_8601, datetime_8601, datetime_th,
+    holiday::HolidayCalendar,
+    ical::{Calendar, Event},
+    js_now,
+    locale::English,
+    pattern::Pattern,
+    range::DateRange,
+    time_8601,
+};
 
 #[wasm_bindgen(start)]
 pub fn main_js() {
@@ -261,6 +273,7 @@ fn render() -> Dom {
                             |d| d.class("rounded-1"),
                             |s| s,
                             always(None),
+                            doms::PickerOptions::default(),
                         ),
                         doms::time_picker(
                             time_mutable.clone(),
@@ -272,6 +285,7 @@ fn render() -> Dom {
                             |d| d.class("rounded-2"),
                             |s| s,
                             always(None),
+                            doms::PickerOptions::default(),
                         ),
                         doms::datetime_picker(
                             datetime_mutable.clone(),
@@ -282,6 +296,158 @@ fn render() -> Dom {
                             |d| d.class(["form-control-sm","rounded-3"]),
                             |s| s,
                             always(None),
+                            doms::PickerOptions::default(),
+                        ),
+                    ])
+                }),
+                html!("div", {
+                    .class(["row","mx-1","my-3","p-2","border"])
+                    .children([
+                        html!("div", {.class("fw-bold").text("English locale, GMT+0")}),
+                        doms::date_picker(
+                            date_mutable.clone(),
+                            changed_mutable.clone(),
+                            disable_mutable.signal(),
+                            None,
+                            |d| d.class(["p-0","my-1"]),
+                            |d| d.class("rounded-1"),
+                            |d| d.class("rounded-1"),
+                            |s| s,
+                            always(None),
+                            doms::PickerOptions { locale: &English, offset: Some(UtcOffset::UTC), ..Default::default() },
+                        ),
+                        doms::time_picker(
+                            time_mutable.clone(),
+                            changed_mutable.clone(),
+                            disable_mutable.signal(),
+                            None,
+                            |d| d.class(["p-0","my-1"]),
+                            |d| d.class("rounded-2"),
+                            |d| d.class("rounded-2"),
+                            |s| s,
+                            always(None),
+                            doms::PickerOptions { locale: &English, offset: Some(UtcOffset::UTC), ..Default::default() },
+                        ),
+                        doms::datetime_picker(
+                            datetime_mutable.clone(),
+                            changed_mutable.clone(),
+                            disable_mutable.signal(),
+                            |d| d.class(["p-0","my-1"]),
+                            |d| d.class(["form-control-sm","rounded-3"]),
+                            |d| d.class(["form-control-sm","rounded-3"]),
+                            |s| s,
+                            always(None),
+                            doms::PickerOptions { locale: &English, offset: Some(UtcOffset::UTC), ..Default::default() },
+                        ),
+                    ])
+                }),
+                html!("div", {
+                    .class(["row","mx-1","my-3","p-2","border"])
+                    .children([
+                        html!("div", {.class("fw-bold").text("Seconds precision")}),
+                        doms::time_picker(
+                            time_mutable.clone(),
+                            changed_mutable.clone(),
+                            disable_mutable.signal(),
+                            None,
+                            |d| d.class(["p-0","my-1"]),
+                            |d| d.class("rounded-2"),
+                            |d| d.class("rounded-2"),
+                            |s| s,
+                            always(None),
+                            doms::PickerOptions { precision: Precision::Seconds, ..Default::default() },
+                        ),
+                        doms::datetime_picker(
+                            datetime_mutable.clone(),
+                            changed_mutable.clone(),
+                            disable_mutable.signal(),
+                            |d| d.class(["p-0","my-1"]),
+                            |d| d.class(["form-control-sm","rounded-3"]),
+                            |d| d.class(["form-control-sm","rounded-3"]),
+                            |s| s,
+                            always(None),
+                            doms::PickerOptions { precision: Precision::Seconds, ..Default::default() },
+                        ),
+                    ])
+                }),
+                html!("div", {
+                    .class(["row","mx-1","my-3","p-2","border"])
+                    .children([
+                        html!("div", {.class("fw-bold").text("Custom patterns, type 31 ส.ค. 68 23:45 and read full Thai date")}),
+                        doms::datetime_picker(
+                            datetime_mutable.clone(),
+                            changed_mutable.clone(),
+                            disable_mutable.signal(),
+                            |d| d.class(["p-0","my-1"]),
+                            |d| d.class("rounded-1"),
+                            |d| d.class("rounded-1"),
+                            |s| s,
+                            always(None),
+                            doms::PickerOptions {
+                                input_pattern: Some(Pattern::new("D MMM BB HH:mm")),
+                                label_pattern: Some(Pattern::new("วันdddd ที่ D MMMM พ.ศ. BBBB เวลา HH:mm น.")),
+                                ..Default::default()
+                            },
+                        ),
+                    ])
+                }),
+                html!("div", {
+                    .class(["row","mx-1","my-3","p-2","border"])
+                    .children([
+                        html!("div", {.class("fw-bold").text("Thai holidays (not selectable) and lunar dates")}),
+                        doms::date_picker(
+                            date_mutable.clone(),
+                            changed_mutable.clone(),
+                            disable_mutable.signal(),
+                            None,
+                            |d| d.class(["p-0","my-1"]),
+                            |d| d.class("rounded-1"),
+                            |d| d.class("rounded-1"),
+                            |s| s,
+                            always(None),
+                            doms::PickerOptions { holidays: Some(Rc::new(HolidayCalendar::new())), forbid_holidays: true, lunar: true, ..Default::default() },
+                        ),
+                    ])
+                }),
+                html!("div", {
+                    .class(["row","mx-1","my-3","p-2","border"])
+                    .children([
+                        html!("div", {.class("fw-bold").text("Business days only, weekend and holidays are not selectable")}),
+                        doms::date_picker(
+                            date_mutable.clone(),
+                            changed_mutable.clone(),
+                            disable_mutable.signal(),
+                            None,
+                            |d| d.class(["p-0","my-1"]),
+                            |d| d.class("rounded-1"),
+                            |d| d.class("rounded-1"),
+                            |s| s,
+                            always(None),
+                            doms::PickerOptions { holidays: Some(Rc::new(HolidayCalendar::new())), business_days: Some(Rc::new(BusinessCalendar::thai())), ..Default::default() },
+                        ),
+                        html!("a", {
+                            .class(["btn","btn-link","text-start"])
+                            .text("Add appointment to calendar (.ics)")
+                            .attr("download", "appointment.ics")
+                            .attr_signal("href", date_mutable.signal_cloned().map(|s| date_8601(&s).and_then(appointment_ics)))
+                        }),
+                    ])
+                }),
+                html!("div", {
+                    .class(["row","mx-1","my-3","p-2","border"])
+                    .children([
+                        html!("div", {.class("fw-bold").text("Birth date, 2 digits year is never in the future")}),
+                        doms::date_picker(
+                            date_mutable.clone(),
+                            changed_mutable.clone(),
+                            disable_mutable.signal(),
+                            None,
+                            |d| d.class(["p-0","my-1"]),
+                            |d| d.class("rounded-1"),
+                            |d| d.class("rounded-1"),
+                            |s| s,
+                            always(None),
+                            doms::PickerOptions { year_pivot: YearPivot::Past, ..Default::default() },
                         ),
                     ])
                 }),
@@ -299,6 +465,7 @@ fn render() -> Dom {
                             |d| d.class("rounded-1"),
                             |s| s,
                             always(None),
+                            doms::PickerOptions::default(),
                         ),
                         doms::time_picker(
                             time_mutable.clone(),
@@ -310,6 +477,7 @@ fn render() -> Dom {
                             |d| d.class("rounded-2"),
                             |s| s,
                             always(None),
+                            doms::PickerOptions::default(),
                         ),
                         doms::datetime_picker(
                             datetime_mutable.clone(),
@@ -320,6 +488,7 @@ fn render() -> Dom {
                             |d| d.class(["form-control-sm","rounded-3"]),
                             |s| s,
                             always(None),
+                            doms::PickerOptions::default(),
                         ),
                     ])
                 }),
@@ -343,6 +512,7 @@ fn render() -> Dom {
                                 }
                             },
                             always(None),
+                            doms::PickerOptions::default(),
                         ),
                         doms::time_picker(
                             time_mutable.clone(),
@@ -360,6 +530,7 @@ fn render() -> Dom {
                                 }
                             },
                             always(None),
+                            doms::PickerOptions::default(),
                         ),
                         doms::datetime_picker(
                             datetime_mutable.clone(),
@@ -376,6 +547,7 @@ fn render() -> Dom {
                                 }
                             },
                             always(None),
+                            doms::PickerOptions::default(),
                         ),
                     ])
                 }),
@@ -465,6 +637,7 @@ fn render() -> Dom {
                                     |d| d.class("rounded-1"),
                                     |s| s,
                                     config_mutable.signal_cloned(),
+                                    doms::PickerOptions::default(),
                                 ),
                                 doms::time_picker(
                                     time_mutable.clone(),
@@ -476,6 +649,7 @@ fn render() -> Dom {
                                     |d| d.class("rounded-2"),
                                     |s| s,
                                     config_mutable.signal_cloned(),
+                                    doms::PickerOptions::default(),
                                 ),
                             ])
                         }),
@@ -488,6 +662,7 @@ fn render() -> Dom {
                             |d| d.class(["form-control-sm","rounded-3"]),
                             |s| s,
                             config_mutable.signal_cloned(),
+                            doms::PickerOptions::default(),
                         ),
                     ])
                 }),
@@ -508,6 +683,7 @@ fn render() -> Dom {
                                 |d| d.class("rounded-2"),
                                 |s| s,
                                 always(None),
+                                doms::PickerOptions::default(),
                             ))
                         }),
                         html!("div", {
@@ -523,6 +699,7 @@ fn render() -> Dom {
                                 |d| d.class("rounded-2"),
                                 |s| s,
                                 always(None),
+                                doms::PickerOptions::default(),
                             ))
                         }),
                         html!("div", {
@@ -537,6 +714,7 @@ fn render() -> Dom {
                                 |d| d.class(["form-control-sm","rounded-2"]),
                                 |s| s,
                                 always(None),
+                                doms::PickerOptions::default(),
                             ))
                         }),
                     ])
@@ -564,6 +742,7 @@ fn render() -> Dom {
                                         |d| d.class("rounded-start-0"),
                                         |s| s,
                                         always(None),
+                                        doms::PickerOptions::default(),
                                     ),
                                 ])
                             }),
@@ -580,6 +759,7 @@ fn render() -> Dom {
                                         |d| d.class("rounded-end-0"),
                                         |s| s,
                                         always(None),
+                                        doms::PickerOptions::default(),
                                     ),
                                     html!("button", {
                                         .attr("type", "button")
@@ -607,6 +787,7 @@ fn render() -> Dom {
                                         |d| d.class(["form-control-sm","rounded-0"]),
                                         |s| s,
                                         always(None),
+                                        doms::PickerOptions::default(),
                                     ),
                                     html!("button", {
                                         .attr("type", "button")
@@ -625,3 +806,12 @@ fn render() -> Dom {
         }))
     })
 }
+
+/// all-day appointment on `date` as iCalendar data URL
+fn appointment_ics(date: Date) -> Option<String> {
+    let days = DateRange::new(date, date)?;
+    let uid = format!("{date}@picker-demo");
+    let calendar = Calendar::new(vec![Event::all_day(&uid, "นัดหมาย", days)]);
+    let ics = String::from(js_sys::encode_uri_component(&calendar.to_string()));
+    Some(["data:text/calendar;charset=utf-8,", &ics].concat())
+}
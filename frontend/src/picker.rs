@@ -4,7 +4,7 @@ use futures_signals::{
     signal::{Mutable, Signal, SignalExt, not},
 };
 use std::rc::Rc;
-use time::{Date, Duration, Month, PrimitiveDateTime, Time, Weekday};
+use time::{Date, Duration, Month, PrimitiveDateTime, Time, UtcOffset, Weekday};
 use time_datepicker_core::{
     config::{
         PickerConfig,
@@ -16,8 +16,17 @@ use time_datepicker_core::{
 };
 
 use picker_util::{
-    JsTime, class, date_8601, datetime_8601, js_now, month_thai, month_thai_full, time_8601,
-    weekday_thai,
+    JsTime, Precision, YearPivot, add_months,
+    business::BusinessCalendar,
+    class,
+    clock::{Clock, JsClock},
+    date_8601, datetime_8601, default_offset,
+    holiday::HolidayCalendar,
+    locale::{Locale, Thai},
+    lunar::{lunar_from_date, lunar_th},
+    offset_label,
+    pattern::Pattern,
+    time_8601,
 };
 
 const DATEPICKER_ROOT: &str = "datepicker-root";
@@ -27,6 +36,7 @@ const DATE_CONTAINER: &str = "datepicker-date-container";
 const TIME_CONTAINER: &str = "datepicker-time-container";
 const HOUR_CONTAINER: &str = "datepicker-hour-container";
 const MINUTE_CONTAINER: &str = "datepicker-minute-container";
+const SECOND_CONTAINER: &str = "datepicker-second-container";
 
 const HEADER: &str = "datepicker-header";
 const BODY: &str = "datepicker-body";
@@ -43,6 +53,7 @@ const TODAY: &str = "datepicker-today";
 
 const HOUR: &str = "datepicker-hour";
 const MINUTE: &str = "datepicker-minute";
+const SECOND: &str = "datepicker-second";
 
 const SELECTABLE: &str = "datepicker-selectable";
 const SELECTED: &str = "datepicker-selected";
@@ -50,6 +61,93 @@ const UNAVAILABLE: &str = "datepicker-unavailable";
 const GRID_HEADER: &str = "datepicker-grid-header";
 const OTHER_MONTH: &str = "datepicker-other-month";
 
+/// display options of the picker, beside `PickerConfig` constraints
+#[derive(Clone)]
+pub struct PickerOptions {
+    /// month and weekday names, era and button labels
+    pub locale: &'static dyn Locale,
+    /// time zone of `now`, `None` will use `picker_util::default_offset()`
+    pub offset: Option<UtcOffset>,
+    /// source of `now`, `FixedClock` for tests and previews
+    pub clock: Rc<dyn Clock>,
+    /// `Seconds` or `Millis` will add seconds column
+    pub precision: Precision,
+    /// holidays are shown in red with the name as tooltip
+    pub holidays: Option<Rc<HolidayCalendar>>,
+    /// holidays can not be selected, needs `holidays`
+    pub forbid_holidays: bool,
+    /// only business days can be selected, weekend and holidays of the calendar are unavailable
+    pub business_days: Option<Rc<BusinessCalendar>>,
+    /// Thai lunar date as tooltip, วันพระ is underlined
+    pub lunar: bool,
+    /// how typed 2 digits year is expanded, `YearPivot::Past` for birth dates
+    pub year_pivot: YearPivot,
+    /// input text, `None` is `DD/MM/BBBB HH:mm` with lenient parsing, ex. `310868 2345`
+    pub input_pattern: Option<Pattern>,
+    /// overlay label, `None` is `Locale::date_pattern()` with `HH:mm น.`
+    pub label_pattern: Option<Pattern>,
+}
+
+impl Default for PickerOptions {
+    fn default() -> Self {
+        Self {
+            locale: &Thai,
+            offset: None,
+            clock: Rc::new(JsClock),
+            precision: Precision::Minutes,
+            holidays: None,
+            forbid_holidays: false,
+            business_days: None,
+            lunar: false,
+            year_pivot: YearPivot::CurrentCentury,
+            input_pattern: None,
+            label_pattern: None,
+        }
+    }
+}
+
+impl PickerOptions {
+    pub fn offset(&self) -> UtcOffset {
+        self.offset.unwrap_or_else(default_offset)
+    }
+
+    /// current datetime at picker's offset
+    pub fn now(&self) -> PrimitiveDateTime {
+        self.clock.now_at(self.offset())
+    }
+
+    /// `now()` moved to the nearest month allowed by `config`, where the picker opens
+    fn default_datetime(&self, config: &PickerConfig<DateConstraints>) -> PrimitiveDateTime {
+        let now = self.now();
+        // before min or after max, look up to 100 years both ways
+        (0..=1200)
+            .flat_map(|n| [n, -n])
+            .filter_map(|n| add_months(&now.date(), n))
+            .map(|date| PrimitiveDateTime::new(date, now.time()))
+            .find(|dt| !config.is_month_forbidden(dt))
+            .unwrap_or(now)
+    }
+
+    fn with_seconds(&self) -> bool {
+        self.precision > Precision::Minutes
+    }
+
+    fn holiday_name(&self, date: &Date) -> Option<String> {
+        self.holidays
+            .as_ref()
+            .and_then(|calendar| calendar.holiday_name(date))
+    }
+
+    /// forbidden holiday or not a business day
+    pub(crate) fn is_day_unavailable(&self, date: &Date) -> bool {
+        (self.forbid_holidays && self.holiday_name(date).is_some())
+            || self
+                .business_days
+                .as_ref()
+                .is_some_and(|calendar| !calendar.is_business_day(date))
+    }
+}
+
 pub struct DatePicker<F: Fn(String) -> String + 'static> {
     /// DateTime or Date or Time
     with_date: bool,
@@ -69,6 +167,7 @@ pub struct DatePicker<F: Fn(String) -> String + 'static> {
     selected_date: Mutable<Option<PrimitiveDateTime>>,
     selected_hour: Mutable<Option<u8>>,
     selected_minute: Mutable<Option<u8>>,
+    selected_second: Mutable<Option<u8>>,
 
     /// viewed date, start with NOW
     viewed_date: Mutable<PrimitiveDateTime>,
@@ -78,6 +177,9 @@ pub struct DatePicker<F: Fn(String) -> String + 'static> {
 
     /// configuration of the picker, should be passed in during init and not modified later
     config: PickerConfig<DateConstraints>,
+
+    /// display options of the picker
+    options: PickerOptions,
 }
 
 impl<F: Fn(String) -> String + 'static> DatePicker<F> {
@@ -88,8 +190,9 @@ impl<F: Fn(String) -> String + 'static> DatePicker<F> {
         container: Mutable<Option<Rc<Self>>>,
         update_fn: F,
         config: PickerConfig<DateConstraints>,
+        options: PickerOptions,
     ) -> Rc<Self> {
-        let default_datetime = config.guess_allowed_year_month();
+        let default_datetime = options.default_datetime(&config);
         let view_type_adjusted = match config.selection_type() {
             DialogViewType::Days => default_datetime,
             DialogViewType::Months => PrimitiveDateTime::new(
@@ -116,9 +219,11 @@ impl<F: Fn(String) -> String + 'static> DatePicker<F> {
             selected_date: Mutable::new(None),
             selected_hour: Mutable::new(None),
             selected_minute: Mutable::new(None),
+            selected_second: Mutable::new(None),
             viewed_date: Mutable::new(viewed_date),
             dialog_view_type: Mutable::new(*config.initial_view_type()),
             config,
+            options,
         })
     }
 
@@ -129,8 +234,9 @@ impl<F: Fn(String) -> String + 'static> DatePicker<F> {
         container: Mutable<Option<Rc<Self>>>,
         update_fn: F,
         config: PickerConfig<DateConstraints>,
+        options: PickerOptions,
     ) -> Rc<Self> {
-        let default_datetime = config.guess_allowed_year_month() - Duration::days(1);
+        let default_datetime = options.default_datetime(&config) - Duration::days(1);
         let viewed_date = paired_mutable
             .and_then(|date_paired| date_8601(&date_paired.get_cloned()))
             .map(|d| PrimitiveDateTime::new(d, default_datetime.time()))
@@ -145,9 +251,11 @@ impl<F: Fn(String) -> String + 'static> DatePicker<F> {
             selected_date: Mutable::new(None),
             selected_hour: Mutable::new(None),
             selected_minute: Mutable::new(None),
+            selected_second: Mutable::new(None),
             viewed_date: Mutable::new(viewed_date),
             dialog_view_type: Mutable::new(*config.initial_view_type()),
             config,
+            options,
         })
     }
 
@@ -157,8 +265,9 @@ impl<F: Fn(String) -> String + 'static> DatePicker<F> {
         container: Mutable<Option<Rc<Self>>>,
         update_fn: F,
         config: PickerConfig<DateConstraints>,
+        options: PickerOptions,
     ) -> Rc<Self> {
-        let default_datetime = config.guess_allowed_year_month();
+        let default_datetime = options.default_datetime(&config);
         let view_type_adjusted = match config.selection_type() {
             DialogViewType::Days => default_datetime,
             DialogViewType::Months => PrimitiveDateTime::new(
@@ -181,9 +290,11 @@ impl<F: Fn(String) -> String + 'static> DatePicker<F> {
             selected_date: Mutable::new(None),
             selected_hour: Mutable::new(None),
             selected_minute: Mutable::new(None),
+            selected_second: Mutable::new(None),
             viewed_date: Mutable::new(view_type_adjusted),
             dialog_view_type: Mutable::new(*config.initial_view_type()),
             config,
+            options,
         })
     }
 
@@ -210,17 +321,29 @@ impl<F: Fn(String) -> String + 'static> DatePicker<F> {
     }
 
     fn create_dialog_title_text(&self) -> impl Signal<Item = String> + use<F> {
+        let locale = self.options.locale;
+        // show offset only when differ from default
+        let offset = self.options.offset.filter(|o| *o != default_offset());
         map_ref! {
             let viewed_date = self.viewed_date.signal(),
             let dialog_view_type = self.dialog_view_type.signal_cloned() =>
-            create_dialog_title_text(dialog_view_type, &viewed_date.date())
+            create_dialog_title_text(dialog_view_type, &viewed_date.date(), locale, offset.as_ref())
         }
     }
 
+    /// time from selected hour, minute and second, `None` when not completed
+    fn selected_time(&self) -> Option<Time> {
+        selected_time(
+            self.selected_hour.get(),
+            self.selected_minute.get(),
+            self.selected_second.get(),
+            self.options.precision,
+        )
+    }
+
     fn set_date(&self, display_date: PrimitiveDateTime, view_type: DialogViewType) {
-        if let (Some(hour), Some(minute)) = (self.selected_hour.get(), self.selected_minute.get()) {
+        if let Some(new_time) = self.selected_time() {
             // datetime mode, time selected
-            let new_time = Time::from_hms(hour, minute, display_date.second()).unwrap();
             let new_date = match view_type {
                 DialogViewType::Days => PrimitiveDateTime::new(display_date.date(), new_time),
                 DialogViewType::Months => PrimitiveDateTime::new(
@@ -232,7 +355,7 @@ impl<F: Fn(String) -> String + 'static> DatePicker<F> {
                     new_time,
                 ),
             };
-            self.apply_update_fn_and_set_mutable(new_date.js_string());
+            self.apply_update_fn_and_set_mutable(new_date.js_string_with(self.options.precision));
             self.container.set(None);
         } else {
             let new_date = match view_type {
@@ -269,6 +392,7 @@ impl<F: Fn(String) -> String + 'static> DatePicker<F> {
                             picker.selected_date.set(datetime_opt);
                             picker.selected_hour.set(datetime_opt.map(|dt| dt.hour()));
                             picker.selected_minute.set(datetime_opt.map(|dt| dt.minute()));
+                            picker.selected_second.set(datetime_opt.map(|dt| dt.second()));
                             if let Some(datetime) = datetime_opt {
                                 picker.viewed_date.set(datetime);
                             }
@@ -284,6 +408,7 @@ impl<F: Fn(String) -> String + 'static> DatePicker<F> {
                             let time_opt = time_8601(&date_mutable).or(picker.config.initial_date().map(|dt| dt.time()));
                             picker.selected_hour.set(time_opt.map(|t| t.hour()));
                             picker.selected_minute.set(time_opt.map(|t| t.minute()));
+                            picker.selected_second.set(time_opt.map(|t| t.second()));
                         }
                         async {}
                     })))
@@ -306,6 +431,7 @@ impl<F: Fn(String) -> String + 'static> DatePicker<F> {
                     .apply_if(picker.with_time, |dom| { dom
                         .child(html!("div", {
                             .class(TIME_CONTAINER)
+                            .apply_if(picker.options.with_seconds(), |dom| dom.style("width", "120px"))
                             .children([
                                 html!("div", {
                                     .class(BODY)
@@ -313,6 +439,9 @@ impl<F: Fn(String) -> String + 'static> DatePicker<F> {
                                         Self::render_dialog_hours(picker.clone()),
                                         Self::render_dialog_minutes(picker.clone()),
                                     ])
+                                    .apply_if(picker.options.with_seconds(), |dom| { dom
+                                        .child(Self::render_dialog_seconds(picker.clone()))
+                                    })
                                 }),
                                 Self::render_time_footer(picker.clone()),
                             ])
@@ -405,7 +534,7 @@ impl<F: Fn(String) -> String + 'static> DatePicker<F> {
                         "hidden"
                     }
                 }))
-                .text("ล้างข้อมูล")
+                .text(picker.options.locale.clear())
                 .event(clone!(picker => move |_:events::Click| {
                     if !picker.date_mutable.get_cloned().is_empty() {
                         picker.date_mutable.set(String::new());
@@ -416,38 +545,39 @@ impl<F: Fn(String) -> String + 'static> DatePicker<F> {
             }))
             .apply(|dom| {
                 if picker.with_time {
+                    let precision = picker.options.precision;
                     dom.child_signal(map_ref! {
                         let selected_hour = picker.selected_hour.signal(),
-                        let selected_minute = picker.selected_minute.signal() =>
-                        (*selected_hour, *selected_minute)
-                    }.map(clone!(picker => move |(selected_hour_opt, selected_minute_opt)| {
-                        if let (Some(selected_hour), Some(selected_minute)) = (selected_hour_opt, selected_minute_opt) {
+                        let selected_minute = picker.selected_minute.signal(),
+                        let selected_second = picker.selected_second.signal() =>
+                        selected_time(*selected_hour, *selected_minute, *selected_second, precision)
+                    }.map(clone!(picker => move |selected_time_opt| {
+                        if let Some(selected_time) = selected_time_opt {
                             // datetime mode with completed time, check to save
-                            // selected_hour and selected_minute comes from defined valid value, cannot panic
-                            let new_datetime = PrimitiveDateTime::new(js_now().date(), Time::from_hms(selected_hour, selected_minute, 0).unwrap());
-                            if picker.config.is_datetime_forbidden(&new_datetime) {
+                            let new_datetime = PrimitiveDateTime::new(picker.options.now().date(), selected_time);
+                            if picker.config.is_datetime_forbidden(&new_datetime) || picker.options.is_day_unavailable(&new_datetime.date()) {
                                 None
                             } else {
                                 Some(html!("button", {
                                     .attr("type", "button")
                                     .class([BUTTON, TODAY])
-                                    .text("วันนี้")
+                                    .text(picker.options.locale.today())
                                     .event(clone!(picker => move |_:events::Click| {
-                                        picker.apply_update_fn_and_set_mutable(new_datetime.js_string());
+                                        picker.apply_update_fn_and_set_mutable(new_datetime.js_string_with(picker.options.precision));
                                         picker.container.set(None);
                                     }))
                                 }))
                             }
                         } else {
                             // datetime mode without time, wait for time
-                            let new_datetime = js_now();
-                            if picker.config.is_datetime_forbidden(&new_datetime) {
+                            let new_datetime = picker.options.now();
+                            if picker.config.is_datetime_forbidden(&new_datetime) || picker.options.is_day_unavailable(&new_datetime.date()) {
                                 None
                             } else {
                                 Some(html!("button", {
                                     .attr("type", "button")
                                     .class([BUTTON, TODAY])
-                                    .text("วันนี้")
+                                    .text(picker.options.locale.today())
                                     .event(clone!(picker => move |_:events::Click| {
                                         picker.selected_date.set(Some(new_datetime));
                                         picker.viewed_date.set(new_datetime);
@@ -458,14 +588,14 @@ impl<F: Fn(String) -> String + 'static> DatePicker<F> {
                     })))
                 } else {
                     // date mode
-                    let new_datetime = js_now();
-                    if picker.config.is_day_forbidden(&new_datetime) {
+                    let new_datetime = picker.options.now();
+                    if picker.config.is_day_forbidden(&new_datetime) || picker.options.is_day_unavailable(&new_datetime.date()) {
                         dom
                     } else {
                         dom.child(html!("button", {
                             .attr("type", "button")
                             .class([BUTTON, TODAY])
-                            .text("วันนี้")
+                            .text(picker.options.locale.today())
                             .event(clone!(picker => move |_:events::Click| {
                                 picker.apply_update_fn_and_set_mutable(new_datetime.date().to_string());
                                 picker.container.set(None);
@@ -480,8 +610,9 @@ impl<F: Fn(String) -> String + 'static> DatePicker<F> {
     fn render_time_footer(picker: Rc<Self>) -> Dom {
         html!("div", {
             .class(FOOTER)
+            .apply_if(picker.options.with_seconds(), |dom| dom.style("width", "120px"))
             .apply(|dom| {
-                let now = js_now();
+                let now = picker.options.now();
                 if picker.with_date {
                     // datetime mode
                     dom.child_signal(picker.selected_date.signal().map(clone!(picker => move |selected_date_opt| {
@@ -493,9 +624,9 @@ impl<F: Fn(String) -> String + 'static> DatePicker<F> {
                                 Some(html!("button", {
                                     .attr("type", "button")
                                     .class([BUTTON, TODAY])
-                                    .text("เวลานี้")
+                                    .text(picker.options.locale.now())
                                     .event(clone!(picker => move |_:events::Click| {
-                                        picker.apply_update_fn_and_set_mutable(new_datetime.js_string());
+                                        picker.apply_update_fn_and_set_mutable(new_datetime.js_string_with(picker.options.precision));
                                         picker.container.set(None);
                                     }))
                                 }))
@@ -504,26 +635,29 @@ impl<F: Fn(String) -> String + 'static> DatePicker<F> {
                             Some(html!("button", {
                                 .attr("type", "button")
                                 .class([BUTTON, TODAY])
-                                .text("เวลานี้")
+                                .text(picker.options.locale.now())
                                 .event(clone!(picker => move |_:events::Click| {
                                     picker.selected_hour.set_neq(Some(now.hour()));
                                     picker.selected_minute.set_neq(Some(now.minute()));
+                                    picker.selected_second.set_neq(Some(now.second()));
                                 }))
                             }))
                         }
                     })))
                 } else {
                     // time mode
+                    let precision = picker.options.precision;
                     dom.child_signal(map_ref!{
                         let selected_hour = picker.selected_hour.signal(),
-                        let selected_minute = picker.selected_minute.signal() =>
-                        (*selected_hour, *selected_minute)
-                    }.map(clone!(picker => move |(selected_hour_opt, selected_minute_opt)| {
-                        if let (Some(selected_hour), Some(selected_minute)) = (selected_hour_opt, selected_minute_opt) && selected_hour == now.hour() && selected_minute == now.minute() {
+                        let selected_minute = picker.selected_minute.signal(),
+                        let selected_second = picker.selected_second.signal() =>
+                        selected_time(*selected_hour, *selected_minute, *selected_second, precision)
+                    }.map(clone!(picker => move |selected_time_opt| {
+                        if selected_time_opt.is_some() && selected_time_opt == selected_time(Some(now.hour()), Some(now.minute()), Some(now.second()), precision) {
                             Some(html!("button", {
                                 .attr("type", "button")
                                 .class([BUTTON, EMPTY])
-                                .text("ล้างข้อมูล")
+                                .text(picker.options.locale.clear())
                                 .event(clone!(picker => move |_:events::Click| {
                                     picker.set_neq_mutable(String::new());
                                     picker.container.set(None);
@@ -533,9 +667,9 @@ impl<F: Fn(String) -> String + 'static> DatePicker<F> {
                             Some(html!("button", {
                                 .attr("type", "button")
                                 .class([BUTTON, TODAY])
-                                .text("เวลานี้")
+                                .text(picker.options.locale.now())
                                 .event(clone!(picker => move |_:events::Click| {
-                                    picker.apply_update_fn_and_set_mutable(now.time().js_string());
+                                    picker.apply_update_fn_and_set_mutable(now.time().js_string_with(picker.options.precision));
                                     picker.container.set(None);
                                 }))
                             }))
@@ -561,7 +695,7 @@ impl<F: Fn(String) -> String + 'static> DatePicker<F> {
     fn render_year_cell(display_year: PrimitiveDateTime, picker: Rc<Self>) -> Dom {
         let is_year_forbidden = picker.config.is_year_forbidden(&display_year);
         html!("span", {
-            .text(&(display_year.year() + 543).to_string())
+            .text(&(display_year.year() + picker.options.locale.era_offset()).to_string())
             .attr("role", "gridcell")
             .class_signal(SELECTED, picker.selected_date.signal_cloned().map(move |opt| {
                 opt.map_or(false, |optval| optval.year() == display_year.year())
@@ -602,7 +736,7 @@ impl<F: Fn(String) -> String + 'static> DatePicker<F> {
     fn render_month_cell(display_month: PrimitiveDateTime, picker: Rc<Self>) -> Dom {
         let is_month_forbidden = picker.config.is_month_forbidden(&display_month);
         html!("span", {
-            .text(&month_thai(&display_month.month()))
+            .text(picker.options.locale.month_short(&display_month.month()))
             .attr("role", "gridcell")
             .class_signal(SELECTED, picker.selected_date.signal_cloned().map(move |opt| {
                 opt.map_or(false, |optval| optval.month() == display_month.month())
@@ -631,13 +765,13 @@ impl<F: Fn(String) -> String + 'static> DatePicker<F> {
             .class(BODY)
             .style("grid-template-columns", "1fr ".repeat(7))
             .children([
-                render_weekday_name(Weekday::Sunday),
-                render_weekday_name(Weekday::Monday),
-                render_weekday_name(Weekday::Tuesday),
-                render_weekday_name(Weekday::Wednesday),
-                render_weekday_name(Weekday::Thursday),
-                render_weekday_name(Weekday::Friday),
-                render_weekday_name(Weekday::Saturday),
+                render_weekday_name(Weekday::Sunday, picker.options.locale),
+                render_weekday_name(Weekday::Monday, picker.options.locale),
+                render_weekday_name(Weekday::Tuesday, picker.options.locale),
+                render_weekday_name(Weekday::Wednesday, picker.options.locale),
+                render_weekday_name(Weekday::Thursday, picker.options.locale),
+                render_weekday_name(Weekday::Friday, picker.options.locale),
+                render_weekday_name(Weekday::Saturday, picker.options.locale),
             ])
             .children_signal_vec(picker.viewed_date.signal().map(clone!(picker => move |d| {
                 let first_day_of_month = d.first_day_of_month();
@@ -651,10 +785,33 @@ impl<F: Fn(String) -> String + 'static> DatePicker<F> {
     }
 
     fn render_day_cell(display_day: PrimitiveDateTime, picker: Rc<Self>) -> Dom {
-        let is_day_forbidden = picker.config.is_day_forbidden(&display_day);
+        let holiday_name = picker.options.holiday_name(&display_day.date());
+        let is_day_forbidden = picker.config.is_day_forbidden(&display_day)
+            || picker.options.is_day_unavailable(&display_day.date());
+        let lunar = if picker.options.lunar {
+            lunar_from_date(&display_day.date())
+        } else {
+            None
+        };
+        // holiday name and lunar date
+        let title = holiday_name
+            .iter()
+            .cloned()
+            .chain(lunar.iter().map(lunar_th))
+            .collect::<Vec<String>>()
+            .join("\n");
         html!("span", {
             .text(&display_day.day().to_string())
             .attr("role", "gridcell")
+            .apply_if(holiday_name.is_some(), |dom| { dom
+                .style("color", "var(--bs-danger)")
+            })
+            .apply_if(lunar.is_some_and(|l| l.is_wan_phra()), |dom| { dom
+                .style("text-decoration", "underline dotted")
+            })
+            .apply_if(!title.is_empty(), |dom| { dom
+                .attr("title", &title)
+            })
             .class_signal(OTHER_MONTH, picker.viewed_date.signal().map(move |viewed_date| viewed_date.month() != display_day.month()))
             .class_signal(SELECTED, picker.selected_date.signal_cloned().map(move |opt| {
                 opt.map_or(false, |optval| optval.date() == display_day.date())
@@ -845,13 +1002,72 @@ impl<F: Fn(String) -> String + 'static> DatePicker<F> {
         })
     }
 
+    fn render_dialog_seconds(picker: Rc<Self>) -> Dom {
+        html!("div", {
+            .class([MINUTE_CONTAINER, SECOND_CONTAINER])
+            .style("border-left", "1px solid var(--bs-border-color)")
+            .child(html!("div", {
+                .class([MINUTE, SECOND])
+                .style("grid-template-columns", "1fr")
+                .children((0..=59u8).map(|s| {
+                    Self::render_second_cell(s, picker.clone())
+                }))
+            }))
+            .with_node!(element => {
+                .future(map_ref! {
+                    let viewed_date = picker.viewed_date.signal(),
+                    let selected_second = picker.selected_second.signal() =>
+                    selected_second.unwrap_or(viewed_date.second())
+                }.for_each(clone!(element => move |second| {
+                    // gap 3px, padding-top 3px, padding-bottom 3px
+                    element.set_scroll_top(second as i32 * 30);
+                    async {}
+                })))
+            })
+        })
+    }
+
+    fn is_second_forbidden_signal(
+        picker: Rc<Self>,
+        display_second: u8,
+    ) -> impl Signal<Item = bool> + use<F> {
+        picker.viewed_date.signal_cloned().map(move |dt| {
+            let display = PrimitiveDateTime::new(
+                dt.date(),
+                Time::from_hms(dt.hour(), dt.minute(), display_second).unwrap(),
+            );
+            picker.config.is_datetime_forbidden(&display)
+        })
+    }
+
+    fn render_second_cell(display_second: u8, picker: Rc<Self>) -> Dom {
+        html!("span", {
+            .text(&display_second.to_string())
+            .class_signal(SELECTED, picker.selected_second.signal_cloned().map(move |opt| opt.map_or(false, |selected_second| selected_second == display_second)))
+            .attr("role", "gridcell")
+            .prop_signal("aria-selected", picker.selected_second.signal_cloned().map(move |opt| {
+                if opt.map_or(false, |selected_second| selected_second == display_second) {"true"} else {"false"}
+            }))
+            .class_signal(UNAVAILABLE, Self::is_second_forbidden_signal(picker.clone(), display_second))
+            .class_signal(SELECTABLE, not(Self::is_second_forbidden_signal(picker.clone(), display_second)))
+            .event(clone!(picker => move |_:events::Click| {
+                let viewed_date = picker.viewed_date.get_cloned();
+                picker.selected_second.set(Some(display_second));
+                // display_second was pre-defined, cannot panic
+                let new_date = PrimitiveDateTime::new(viewed_date.date(), Time::from_hms(viewed_date.hour(), viewed_date.minute(), display_second).unwrap());
+                picker.viewed_date.set(new_date);
+                picker.selected_date.set(Some(new_date));
+            }))
+        })
+    }
+
     fn render_exit(picker: Rc<Self>) -> Dom {
         html!("button", {
             .attr("type", "button")
             .class([BUTTON, CLOSE])
             .apply(|dom| {
                 if picker.with_time { dom
-                    .text("บันทึก")
+                    .text(picker.options.locale.save())
                 } else { dom
                     .child(html!("i", {.class(class::FA_X)}))
                 }
@@ -867,13 +1083,9 @@ impl<F: Fn(String) -> String + 'static> DatePicker<F> {
             if let Some(selected_date) = self.selected_date.get_cloned() {
                 let iso = if self.with_time {
                     // always use new select_hour and select_minute
-                    if let (Some(selected_hour), Some(selected_minute)) =
-                        (self.selected_hour.get(), self.selected_minute.get())
-                    {
-                        // selected_hour and selected_minute comes from defined valid value, cannot panic
-                        let selected_time =
-                            Time::from_hms(selected_hour, selected_minute, 0).unwrap();
-                        PrimitiveDateTime::new(selected_date.date(), selected_time).js_string()
+                    if let Some(selected_time) = self.selected_time() {
+                        PrimitiveDateTime::new(selected_date.date(), selected_time)
+                            .js_string_with(self.options.precision)
                     } else {
                         String::new()
                     }
@@ -887,12 +1099,10 @@ impl<F: Fn(String) -> String + 'static> DatePicker<F> {
                 }
             }
         } else if self.with_time {
-            if let (Some(selected_hour), Some(selected_minute)) =
-                (self.selected_hour.get(), self.selected_minute.get())
-            {
-                // selected_hour and selected_minute comes from defined valid value, cannot panic
-                let selected_time = Time::from_hms(selected_hour, selected_minute, 0).unwrap();
-                self.apply_update_fn_and_set_mutable(selected_time.js_string());
+            if let Some(selected_time) = self.selected_time() {
+                self.apply_update_fn_and_set_mutable(
+                    selected_time.js_string_with(self.options.precision),
+                );
             } else {
                 self.set_neq_mutable(String::new());
             }
@@ -919,34 +1129,55 @@ impl<F: Fn(String) -> String + 'static> DatePicker<F> {
     }
 }
 
-fn render_weekday_name(day: Weekday) -> Dom {
+/// time from selected parts, second is used only when `precision` has seconds
+fn selected_time(
+    hour: Option<u8>,
+    minute: Option<u8>,
+    second: Option<u8>,
+    precision: Precision,
+) -> Option<Time> {
+    let second = if precision > Precision::Minutes {
+        second.unwrap_or_default()
+    } else {
+        0
+    };
+    // selected parts comes from defined valid value
+    Time::from_hms(hour?, minute?, second).ok()
+}
+
+fn render_weekday_name(day: Weekday, locale: &dyn Locale) -> Dom {
     html!("span", {
-        .text(weekday_thai(&day))
+        .text(locale.weekday_short(&day))
         .class(GRID_HEADER)
         .attr("role", "columnheader")
     })
 }
 
-/// Creates the text that should be the title of the datepicker dialog.
-pub fn create_dialog_title_text(dialog_view_type: &DialogViewType, viewed_date: &Date) -> String {
-    match dialog_view_type {
-        DialogViewType::Days => format!(
-            "{} {}",
-            month_thai_full(&viewed_date.month()),
-            viewed_date.year() + 543
-        ),
-        DialogViewType::Months => (viewed_date.year() + 543).to_string(),
-        DialogViewType::Years => format!(
-            "{} - {}",
-            year_group_start(viewed_date.year() + 543),
-            year_group_end(viewed_date.year() + 543)
-        ),
+/// Creates the text that should be the title of the datepicker dialog.<br>
+/// `offset` will be appended, ex. `สิงหาคม 2568 (GMT+0)`
+pub fn create_dialog_title_text(
+    dialog_view_type: &DialogViewType,
+    viewed_date: &Date,
+    locale: &dyn Locale,
+    offset: Option<&UtcOffset>,
+) -> String {
+    let year = viewed_date.year() + locale.era_offset();
+    let title = match dialog_view_type {
+        DialogViewType::Days => format!("{} {}", locale.month_full(&viewed_date.month()), year),
+        DialogViewType::Months => year.to_string(),
+        DialogViewType::Years => format!("{} - {}", year_group_start(year), year_group_end(year)),
+    };
+    if let Some(o) = offset {
+        format!("{} ({})", title, offset_label(o))
+    } else {
+        title
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use picker_util::locale::English;
     use rstest::*;
     use time_datepicker_core::{
         utils::from_ymd,
@@ -973,7 +1204,49 @@ mod tests {
     ) {
         assert_eq!(
             expected,
-            create_dialog_title_text(&dialog_view_type, &viewed_date)
+            create_dialog_title_text(&dialog_view_type, &viewed_date, &Thai, None)
+        );
+    }
+
+    #[rstest(
+        expected,
+        dialog_view_type,
+        viewed_date,
+        case::days_default("January 1990", DialogViewType::Days, create_date(1990, 1, 1)),
+        case::months("1990", DialogViewType::Months, create_date(1990, 1, 1)),
+        case::years("1980 - 1999", DialogViewType::Years, create_date(1990, 1, 1))
+    )]
+    fn test_create_dialog_title_text_english(
+        expected: &str,
+        dialog_view_type: DialogViewType,
+        viewed_date: Date,
+    ) {
+        assert_eq!(
+            expected,
+            create_dialog_title_text(&dialog_view_type, &viewed_date, &English, None)
+        );
+    }
+
+    #[rstest(
+        expected,
+        dialog_view_type,
+        viewed_date,
+        case::days_default("มกราคม 2533 (GMT+0)", DialogViewType::Days, create_date(1990, 1, 1)),
+        case::months("2533 (GMT+0)", DialogViewType::Months, create_date(1990, 1, 1))
+    )]
+    fn test_create_dialog_title_text_offset(
+        expected: &str,
+        dialog_view_type: DialogViewType,
+        viewed_date: Date,
+    ) {
+        assert_eq!(
+            expected,
+            create_dialog_title_text(
+                &dialog_view_type,
+                &viewed_date,
+                &Thai,
+                Some(&UtcOffset::UTC)
+            )
         );
     }
 }
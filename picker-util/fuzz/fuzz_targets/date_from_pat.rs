@@ -0,0 +1,18 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use picker_util::{date_from_pat, date_pat, try_date_from_pat};
+
+fuzz_target!(|text: &str| {
+    match try_date_from_pat(text) {
+        // Buddhist era 544 to 9999 is 1 CE to 9456 CE
+        Ok(date) if (1..=9456).contains(&date.year()) => {
+            assert_eq!(date_from_pat(&date_pat(&date)), Some(date), "{text:?}");
+        }
+        Ok(_) => {}
+        Err(e) => assert!(
+            e.position().is_none_or(|p| p <= text.len()),
+            "{e:?} in {text:?}"
+        ),
+    }
+});
@@ -0,0 +1,22 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use picker_util::{datetime_from_pat, datetime_pat, try_datetime_from_pat};
+
+fuzz_target!(|text: &str| {
+    match try_datetime_from_pat(text) {
+        // Buddhist era 544 to 9999 is 1 CE to 9456 CE
+        Ok(date_time) if (1..=9456).contains(&date_time.year()) => {
+            assert_eq!(
+                datetime_from_pat(&datetime_pat(&date_time)),
+                Some(date_time),
+                "{text:?}"
+            );
+        }
+        Ok(_) => {}
+        Err(e) => assert!(
+            e.position().is_none_or(|p| p <= text.len()),
+            "{e:?} in {text:?}"
+        ),
+    }
+});
@@ -0,0 +1,23 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use picker_util::{Precision, time_pat_with, try_time_from_pat_with};
+
+fuzz_target!(|text: &str| {
+    for precision in [Precision::Minutes, Precision::Seconds, Precision::Millis] {
+        match try_time_from_pat_with(text, precision) {
+            Ok(time) => {
+                let printed = time_pat_with(&time, precision);
+                assert_eq!(
+                    try_time_from_pat_with(&printed, precision),
+                    Ok(time),
+                    "{text:?}"
+                );
+            }
+            Err(e) => assert!(
+                e.position().is_none_or(|p| p <= text.len()),
+                "{e:?} in {text:?}"
+            ),
+        }
+    }
+});
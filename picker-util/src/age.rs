@@ -0,0 +1,99 @@
+use time::Date;
+
+use crate::{
+    add_months,
+    clock::default_clock,
+    locale::{Locale, Thai},
+};
+
+/// calendar age, `45 ปี 3 เดือน 12 วัน`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Age {
+    pub years: u32,
+    pub months: u32,
+    pub days: u32,
+}
+
+/// age from `birth` to today at default offset, `None` if born in the future
+pub fn age(birth: &Date) -> Option<Age> {
+    age_at(birth, &default_clock().today())
+}
+/// age from `birth` to `at`, `None` if `at` is before `birth`<br>
+/// - months are counted by calendar, `31 ม.ค.` is 1 month old at `28 ก.พ.`
+/// - `29 ก.พ.` birthday is `28 ก.พ.` in common years
+pub fn age_at(birth: &Date, at: &Date) -> Option<Age> {
+    if at < birth {
+        return None;
+    }
+    let mut months =
+        (at.year() - birth.year()) as i64 * 12 + at.month() as i64 - birth.month() as i64;
+    let mut anchor = add_months(birth, months)?;
+    if anchor > *at {
+        months -= 1;
+        anchor = add_months(birth, months)?;
+    }
+
+    Some(Age {
+        years: (months / 12) as u32,
+        months: (months % 12) as u32,
+        days: (*at - anchor).whole_days() as u32,
+    })
+}
+
+/// format Age to `อายุ 45 ปี 3 เดือน 12 วัน`
+pub fn age_th(age: &Age) -> String {
+    ["อายุ ", &age_locale(age, &Thai)].concat()
+}
+/// format Age to localized `45 ปี 3 เดือน 12 วัน` or `45 years 3 months 12 days`<br>
+/// zero parts are skipped, newborn will be `0 วัน`
+pub fn age_locale(age: &Age, locale: &dyn Locale) -> String {
+    let mut parts = Vec::new();
+    if age.years != 0 {
+        parts.push(locale.years(age.years as i64));
+    }
+    if age.months != 0 {
+        parts.push(locale.months(age.months as i64));
+    }
+    if age.days != 0 || parts.is_empty() {
+        parts.push(locale.days(age.days as i64));
+    }
+    parts.join(" ")
+}
+
+#[cfg(test)]
+#[rustfmt::skip]
+pub mod tests {
+
+    use super::*;
+    use crate::locale::English;
+    use time::macros::date;
+
+    fn ymd(years: u32, months: u32, days: u32) -> Option<Age> {
+        Some(Age { years, months, days })
+    }
+
+    #[test]
+    fn test_age_at() {
+        assert_eq!(age_at(&date!(1978-08-24), &date!(2024-12-05)), ymd(46, 3, 11));
+        assert_eq!(age_at(&date!(1978-08-24), &date!(1978-08-24)), ymd(0, 0, 0));
+        assert_eq!(age_at(&date!(1978-08-24), &date!(2024-08-23)), ymd(45, 11, 30));
+        assert_eq!(age_at(&date!(1978-08-24), &date!(1978-08-23)), None);
+        // end of month
+        assert_eq!(age_at(&date!(2024-01-31), &date!(2024-02-29)), ymd(0, 1, 0));
+        assert_eq!(age_at(&date!(2024-01-31), &date!(2024-03-01)), ymd(0, 1, 1));
+        assert_eq!(age_at(&date!(2024-01-31), &date!(2024-03-30)), ymd(0, 1, 30));
+        assert_eq!(age_at(&date!(2024-01-31), &date!(2024-03-31)), ymd(0, 2, 0));
+        // 29 Feb
+        assert_eq!(age_at(&date!(2020-02-29), &date!(2021-02-27)), ymd(0, 11, 29));
+        assert_eq!(age_at(&date!(2020-02-29), &date!(2021-02-28)), ymd(1, 0, 0));
+        assert_eq!(age_at(&date!(2020-02-29), &date!(2024-02-29)), ymd(4, 0, 0));
+    }
+
+    #[test]
+    fn test_age_locale() {
+        assert_eq!(age_th(&Age { years: 45, months: 3, days: 12 }), String::from("อายุ 45 ปี 3 เดือน 12 วัน"));
+        assert_eq!(age_th(&Age { years: 1, months: 0, days: 2 }), String::from("อายุ 1 ปี 2 วัน"));
+        assert_eq!(age_th(&Age::default()), String::from("อายุ 0 วัน"));
+        assert_eq!(age_locale(&Age { years: 45, months: 1, days: 12 }, &English), String::from("45 years 1 month 12 days"));
+    }
+}
@@ -0,0 +1,199 @@
+use std::{
+    collections::{BTreeSet, HashSet},
+    rc::Rc,
+};
+use time::{Date, Weekday};
+
+use crate::holiday::HolidayCalendar;
+
+/// days off beside weekend, `HolidayCalendar` or any set of dates
+pub trait HolidaySet {
+    fn is_holiday(&self, date: &Date) -> bool;
+}
+
+impl HolidaySet for HolidayCalendar {
+    fn is_holiday(&self, date: &Date) -> bool {
+        HolidayCalendar::is_holiday(self, date)
+    }
+}
+
+impl HolidaySet for Vec<Date> {
+    fn is_holiday(&self, date: &Date) -> bool {
+        self.contains(date)
+    }
+}
+
+impl HolidaySet for BTreeSet<Date> {
+    fn is_holiday(&self, date: &Date) -> bool {
+        self.contains(date)
+    }
+}
+
+impl HolidaySet for HashSet<Date> {
+    fn is_holiday(&self, date: &Date) -> bool {
+        self.contains(date)
+    }
+}
+
+impl<H: HolidaySet + ?Sized> HolidaySet for &H {
+    fn is_holiday(&self, date: &Date) -> bool {
+        (**self).is_holiday(date)
+    }
+}
+
+impl<H: HolidaySet + ?Sized> HolidaySet for Rc<H> {
+    fn is_holiday(&self, date: &Date) -> bool {
+        (**self).is_holiday(date)
+    }
+}
+
+/// working days, not weekend and not holiday
+#[derive(Clone, Debug)]
+pub struct BusinessCalendar<H = HolidayCalendar> {
+    weekend: Vec<Weekday>,
+    holidays: H,
+}
+
+impl Default for BusinessCalendar {
+    fn default() -> Self {
+        Self::thai()
+    }
+}
+
+impl BusinessCalendar {
+    /// Saturday and Sunday weekend with Thai public holidays
+    pub fn thai() -> Self {
+        Self::new(HolidayCalendar::new())
+    }
+}
+
+impl<H: HolidaySet> BusinessCalendar<H> {
+    /// Saturday and Sunday weekend with `holidays`
+    pub fn new(holidays: H) -> Self {
+        Self {
+            weekend: vec![Weekday::Saturday, Weekday::Sunday],
+            holidays,
+        }
+    }
+
+    /// replace weekend days, ex. `[Weekday::Friday]`
+    pub fn with_weekend<I: IntoIterator<Item = Weekday>>(mut self, weekend: I) -> Self {
+        self.weekend = weekend.into_iter().collect();
+        self.weekend
+            .sort_by_key(|day| day.number_days_from_sunday());
+        self.weekend.dedup();
+        self
+    }
+
+    pub fn weekend(&self) -> &[Weekday] {
+        &self.weekend
+    }
+
+    pub fn holidays(&self) -> &H {
+        &self.holidays
+    }
+
+    pub fn is_business_day(&self, date: &Date) -> bool {
+        !self.weekend.contains(&date.weekday()) && !self.holidays.is_holiday(date)
+    }
+
+    /// the first business day after `date`, `None` if every weekday is weekend
+    pub fn next_business_day(&self, date: &Date) -> Option<Date> {
+        self.step(date, Date::next_day)
+    }
+
+    /// the last business day before `date`, `None` if every weekday is weekend
+    pub fn previous_business_day(&self, date: &Date) -> Option<Date> {
+        self.step(date, Date::previous_day)
+    }
+
+    /// move `days` business days from `date`, negative goes back, `0` is `date` itself<br>
+    /// `date` needs not be a business day, Friday + 1 is Monday, Saturday + 1 is Monday too
+    pub fn add_business_days(&self, date: &Date, days: i64) -> Option<Date> {
+        let mut date = *date;
+        for _ in 0..days.unsigned_abs() {
+            date = if days > 0 {
+                self.next_business_day(&date)?
+            } else {
+                self.previous_business_day(&date)?
+            };
+        }
+        Some(date)
+    }
+
+    /// business days after `start` until `end`, negative if `end` is before `start`<br>
+    /// Monday to Friday of the same week is 4
+    pub fn business_days_between(&self, start: &Date, end: &Date) -> i64 {
+        let (from, to, sign) = if start <= end {
+            (*start, *end, 1)
+        } else {
+            (*end, *start, -1)
+        };
+        let count = std::iter::successors(from.next_day(), |date| date.next_day())
+            .take_while(|date| *date <= to)
+            .filter(|date| self.is_business_day(date))
+            .count() as i64;
+        count * sign
+    }
+
+    fn step(&self, date: &Date, next: fn(Date) -> Option<Date>) -> Option<Date> {
+        if self.weekend.len() >= 7 {
+            return None;
+        }
+        let mut date = *date;
+        loop {
+            date = next(date)?;
+            if self.is_business_day(&date) {
+                return Some(date);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[rustfmt::skip]
+pub mod tests {
+
+    use super::*;
+    use time::macros::date;
+
+    #[test]
+    fn test_business_day() {
+        let calendar = BusinessCalendar::thai();
+        assert!(calendar.is_business_day(&date!(2025-08-06)));
+        assert!(!calendar.is_business_day(&date!(2025-08-09)));
+        assert!(!calendar.is_business_day(&date!(2025-08-12)));
+        // Friday to Monday
+        assert_eq!(calendar.next_business_day(&date!(2025-08-08)), Some(date!(2025-08-11)));
+        // สงกรานต์ 13-15 and วันหยุดชดเชย 16 เม.ย. 2568
+        assert_eq!(calendar.next_business_day(&date!(2025-04-11)), Some(date!(2025-04-17)));
+        assert_eq!(calendar.previous_business_day(&date!(2025-04-17)), Some(date!(2025-04-11)));
+        let calendar = BusinessCalendar::new(Vec::new()).with_weekend(std::iter::successors(Some(Weekday::Monday), |day| Some(day.next())).take(7));
+        assert_eq!(calendar.next_business_day(&date!(2025-08-08)), None);
+    }
+
+    #[test]
+    fn test_add_business_days() {
+        let calendar = BusinessCalendar::thai();
+        assert_eq!(calendar.add_business_days(&date!(2025-08-06), 0), Some(date!(2025-08-06)));
+        assert_eq!(calendar.add_business_days(&date!(2025-08-06), 5), Some(date!(2025-08-14)));
+        assert_eq!(calendar.add_business_days(&date!(2025-08-09), 1), Some(date!(2025-08-11)));
+        assert_eq!(calendar.add_business_days(&date!(2025-08-14), -5), Some(date!(2025-08-06)));
+        // Friday and Saturday weekend
+        let calendar = BusinessCalendar::new(BTreeSet::from([date!(2025-08-10)])).with_weekend([Weekday::Saturday, Weekday::Friday]);
+        assert_eq!(calendar.weekend(), &[Weekday::Friday, Weekday::Saturday]);
+        assert_eq!(calendar.add_business_days(&date!(2025-08-07), 1), Some(date!(2025-08-11)));
+    }
+
+    #[test]
+    fn test_business_days_between() {
+        let calendar = BusinessCalendar::thai();
+        assert_eq!(calendar.business_days_between(&date!(2025-08-04), &date!(2025-08-08)), 4);
+        assert_eq!(calendar.business_days_between(&date!(2025-08-08), &date!(2025-08-04)), -4);
+        assert_eq!(calendar.business_days_between(&date!(2025-08-06), &date!(2025-08-06)), 0);
+        // วันแม่ 12 ส.ค. 2568
+        assert_eq!(calendar.business_days_between(&date!(2025-08-08), &date!(2025-08-15)), 4);
+        let holidays = Rc::new(HolidayCalendar::fixed());
+        assert_eq!(BusinessCalendar::new(holidays).business_days_between(&date!(2025-08-08), &date!(2025-08-15)), 4);
+    }
+}
@@ -0,0 +1,109 @@
+use time::{Date, OffsetDateTime, PrimitiveDateTime, UtcOffset};
+
+use crate::default_offset;
+#[cfg(feature = "wasm")]
+use crate::get_now_wasm;
+
+/// source of the current time, every `now` and `today` in this crate comes from a clock<br>
+/// `JsClock` in browser, `SystemClock` in native and `FixedClock` for tests
+pub trait Clock {
+    /// current UTC datetime
+    fn now_utc(&self) -> OffsetDateTime;
+
+    /// datetime at `offset`
+    fn now_at(&self, offset: UtcOffset) -> PrimitiveDateTime {
+        let local = self.now_utc().to_offset(offset);
+        PrimitiveDateTime::new(local.date(), local.time())
+    }
+
+    /// datetime at `default_offset()`
+    fn now(&self) -> PrimitiveDateTime {
+        self.now_at(default_offset())
+    }
+
+    /// date at `default_offset()`
+    fn today(&self) -> Date {
+        self.now().date()
+    }
+}
+
+/// `OffsetDateTime::now_utc()`, needs `native` feature
+#[cfg(feature = "native")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+
+#[cfg(feature = "native")]
+impl Clock for SystemClock {
+    fn now_utc(&self) -> OffsetDateTime {
+        OffsetDateTime::now_utc()
+    }
+}
+
+/// `js_sys::Date::now()` in seconds, needs `wasm` feature, panics outside browser
+#[cfg(feature = "wasm")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct JsClock;
+
+#[cfg(feature = "wasm")]
+impl Clock for JsClock {
+    fn now_utc(&self) -> OffsetDateTime {
+        OffsetDateTime::from_unix_timestamp(get_now_wasm() as i64)
+            .unwrap_or(OffsetDateTime::UNIX_EPOCH)
+    }
+}
+
+/// always the same time, for tests and previews
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FixedClock(pub OffsetDateTime);
+
+impl FixedClock {
+    /// `date_time` at `offset`, ex. `FixedClock::at(datetime!(2025-08-06 10:00), offset!(+7))`
+    pub fn at(date_time: PrimitiveDateTime, offset: UtcOffset) -> Self {
+        Self(date_time.assume_offset(offset))
+    }
+}
+
+impl Clock for FixedClock {
+    fn now_utc(&self) -> OffsetDateTime {
+        self.0
+    }
+}
+
+#[cfg(not(any(feature = "native", all(feature = "wasm", target_arch = "wasm32"))))]
+compile_error!("picker-util needs `native` feature, `wasm` feature alone works on wasm32 only");
+
+/// `JsClock` in wasm32, `SystemClock` otherwise
+pub fn default_clock() -> &'static dyn Clock {
+    #[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+    {
+        &JsClock
+    }
+    #[cfg(all(feature = "native", not(all(feature = "wasm", target_arch = "wasm32"))))]
+    {
+        &SystemClock
+    }
+}
+
+#[cfg(test)]
+#[rustfmt::skip]
+pub mod tests {
+
+    use super::*;
+    use time::macros::{date, datetime, offset};
+
+    #[test]
+    fn test_fixed_clock() {
+        let clock = FixedClock::at(datetime!(2025-08-06 23:30), offset!(+7));
+        assert_eq!(clock.now(), datetime!(2025-08-06 23:30));
+        assert_eq!(clock.today(), date!(2025-08-06));
+        assert_eq!(clock.now_at(offset!(UTC)), datetime!(2025-08-06 16:30));
+        assert_eq!(clock.now_at(offset!(+9)).date(), date!(2025-08-07));
+    }
+
+    #[cfg(feature = "native")]
+    #[test]
+    fn test_system_clock() {
+        assert!(SystemClock.today() > date!(2025-01-01));
+        assert!(default_clock().now_utc() > datetime!(2025-01-01 00:00).assume_utc());
+    }
+}
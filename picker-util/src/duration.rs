@@ -0,0 +1,209 @@
+use time::Duration;
+
+use crate::{ParseError, parse_th::Cursor};
+
+/// smallest unit shown by `duration_th()`, smaller units are truncated
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DurationUnit {
+    Days,
+    Hours,
+    #[default]
+    Minutes,
+    Seconds,
+}
+
+impl DurationUnit {
+    fn seconds(&self) -> i64 {
+        match self {
+            Self::Days => 86_400,
+            Self::Hours => 3_600,
+            Self::Minutes => 60,
+            Self::Seconds => 1,
+        }
+    }
+
+    fn name(&self, style: DurationStyle) -> &'static str {
+        match (self, style) {
+            (Self::Days, DurationStyle::Long) => " วัน",
+            (Self::Hours, DurationStyle::Long) => " ชั่วโมง",
+            (Self::Minutes, DurationStyle::Long) => " นาที",
+            (Self::Seconds, DurationStyle::Long) => " วินาที",
+            (Self::Days, DurationStyle::Compact) => "วัน",
+            (Self::Hours, DurationStyle::Compact) => "ชม.",
+            (Self::Minutes, DurationStyle::Compact) => "น.",
+            (Self::Seconds, DurationStyle::Compact) => "วิ.",
+        }
+    }
+}
+
+/// `1 ชั่วโมง 30 นาที` or `1ชม. 30น.`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DurationStyle {
+    #[default]
+    Long,
+    Compact,
+}
+
+const UNITS: [DurationUnit; 4] = [
+    DurationUnit::Days,
+    DurationUnit::Hours,
+    DurationUnit::Minutes,
+    DurationUnit::Seconds,
+];
+
+// longer names first, `วินาที` before `วิ`
+const UNIT_NAMES: [(&str, DurationUnit); 9] = [
+    ("วินาที", DurationUnit::Seconds),
+    ("วิ.", DurationUnit::Seconds),
+    ("วิ", DurationUnit::Seconds),
+    ("วัน", DurationUnit::Days),
+    ("ชั่วโมง", DurationUnit::Hours),
+    ("ชม.", DurationUnit::Hours),
+    ("ชม", DurationUnit::Hours),
+    ("นาที", DurationUnit::Minutes),
+    ("น.", DurationUnit::Minutes),
+];
+
+/// format Duration to `1 วัน 2 ชั่วโมง 30 นาที` down to `smallest` unit<br>
+/// - zero units are skipped, zero duration will be `0 นาที`
+/// - negative duration has `-` prefix, ex. `-1ชม. 30น.`
+pub fn duration_th(duration: Duration, smallest: DurationUnit, style: DurationStyle) -> String {
+    let secs = duration.whole_seconds();
+    let mut rest = secs.unsigned_abs();
+    let mut parts = Vec::new();
+    for unit in UNITS.iter().filter(|unit| **unit <= smallest) {
+        let size = unit.seconds() as u64;
+        if rest >= size {
+            parts.push([&(rest / size).to_string(), unit.name(style)].concat());
+            rest %= size;
+        }
+    }
+    if parts.is_empty() {
+        return ["0", smallest.name(style)].concat();
+    }
+    let text = parts.join(" ");
+    if secs < 0 {
+        ["-", &text].concat()
+    } else {
+        text
+    }
+}
+
+/// parse Thai duration `1 ชั่วโมง 30 นาที`, `1ชม. 30น.`, `90 นาที` or `1:30` to time::Duration, error will be `None`
+pub fn duration_from_th(text: &str) -> Option<Duration> {
+    try_duration_from_th(text).ok()
+}
+/// same as `duration_from_th()`, error will be `ParseError`<br>
+/// - `H:MM` or `H:MM:SS`, hours can be more than 23
+/// - number without unit is minutes, ex. `1 ชั่วโมง 30`
+/// - `-` prefix for negative duration
+pub fn try_duration_from_th(text: &str) -> Result<Duration, ParseError> {
+    let mut cursor = Cursor::new(text)?;
+    let negative = cursor.eat("-");
+    if !negative {
+        cursor.eat("+");
+    }
+    let (_, first, _) = cursor.number()?;
+    let mut duration = if cursor.eat(":") {
+        let (m_position, minute, _) = cursor.number()?;
+        if minute > 59 {
+            return Err(ParseError::MinuteOutOfRange {
+                position: m_position,
+                minute,
+            });
+        }
+        let second = if cursor.eat(":") {
+            let (s_position, second, _) = cursor.number()?;
+            if second > 59 {
+                return Err(ParseError::SecondOutOfRange {
+                    position: s_position,
+                    second,
+                });
+            }
+            second
+        } else {
+            0
+        };
+        Duration::hours(first as i64)
+            + Duration::minutes(minute as i64)
+            + Duration::seconds(second as i64)
+    } else {
+        let mut duration = Duration::ZERO;
+        let mut amount = first;
+        loop {
+            duration += Duration::seconds(amount as i64 * unit(&mut cursor)?.seconds());
+            if cursor.rest().is_empty() {
+                break;
+            }
+            amount = cursor.number()?.1;
+        }
+        duration
+    };
+    cursor.end()?;
+
+    if negative {
+        duration = -duration;
+    }
+    Ok(duration)
+}
+
+/// unit after a number, minutes when nothing follows
+fn unit(cursor: &mut Cursor) -> Result<DurationUnit, ParseError> {
+    let rest = cursor.rest();
+    let is_digit = |c: char| c.is_ascii_digit() || ('๐'..='๙').contains(&c);
+    if rest.is_empty() || rest.starts_with(is_digit) {
+        return Ok(DurationUnit::Minutes);
+    }
+    match UNIT_NAMES.iter().find(|(name, _)| rest.starts_with(name)) {
+        Some((name, unit)) => {
+            cursor.position += name.len();
+            Ok(*unit)
+        }
+        None => Err(ParseError::UnknownUnit {
+            position: cursor.position,
+        }),
+    }
+}
+
+#[cfg(test)]
+#[rustfmt::skip]
+pub mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_duration_th() {
+        let d = Duration::days(1) + Duration::hours(2) + Duration::minutes(30) + Duration::seconds(15);
+        assert_eq!(duration_th(d, DurationUnit::Seconds, DurationStyle::Long), String::from("1 วัน 2 ชั่วโมง 30 นาที 15 วินาที"));
+        assert_eq!(duration_th(d, DurationUnit::Minutes, DurationStyle::Long), String::from("1 วัน 2 ชั่วโมง 30 นาที"));
+        assert_eq!(duration_th(d, DurationUnit::Hours, DurationStyle::Compact), String::from("1วัน 2ชม."));
+        assert_eq!(duration_th(Duration::minutes(90), DurationUnit::Minutes, DurationStyle::Compact), String::from("1ชม. 30น."));
+        assert_eq!(duration_th(Duration::minutes(-90), DurationUnit::Minutes, DurationStyle::Compact), String::from("-1ชม. 30น."));
+        assert_eq!(duration_th(Duration::seconds(59), DurationUnit::Minutes, DurationStyle::Long), String::from("0 นาที"));
+        assert_eq!(duration_th(Duration::seconds(-59), DurationUnit::Minutes, DurationStyle::Long), String::from("0 นาที"));
+        assert_eq!(duration_th(Duration::hours(49), DurationUnit::Seconds, DurationStyle::Long), String::from("2 วัน 1 ชั่วโมง"));
+    }
+
+    #[test]
+    fn test_duration_from_th() {
+        assert_eq!(duration_from_th("1 ชั่วโมง 30 นาที"), Some(Duration::minutes(90)));
+        assert_eq!(duration_from_th("90 นาที"), Some(Duration::minutes(90)));
+        assert_eq!(duration_from_th("1:30"), Some(Duration::minutes(90)));
+        assert_eq!(duration_from_th("26:00:15"), Some(Duration::hours(26) + Duration::seconds(15)));
+        assert_eq!(duration_from_th("1ชม. 30น."), Some(Duration::minutes(90)));
+        assert_eq!(duration_from_th("1 ชั่วโมง 30"), Some(Duration::minutes(90)));
+        assert_eq!(duration_from_th("90"), Some(Duration::minutes(90)));
+        assert_eq!(duration_from_th("2 วัน 15 วินาที"), Some(Duration::days(2) + Duration::seconds(15)));
+        assert_eq!(duration_from_th("-๑ ชม"), Some(Duration::hours(-1)));
+        // round trip
+        let d = Duration::days(1) + Duration::hours(2) + Duration::minutes(30) + Duration::seconds(15);
+        assert_eq!(duration_from_th(&duration_th(d, DurationUnit::Seconds, DurationStyle::Long)), Some(d));
+        assert_eq!(duration_from_th(&duration_th(-d, DurationUnit::Seconds, DurationStyle::Compact)), Some(-d));
+        // failed
+        assert_eq!(try_duration_from_th(" "), Err(ParseError::Empty));
+        assert_eq!(try_duration_from_th("1:60"), Err(ParseError::MinuteOutOfRange { position: 2, minute: 60 }));
+        assert_eq!(try_duration_from_th("1 ชั่ว"), Err(ParseError::UnknownUnit { position: 2 }));
+        assert_eq!(try_duration_from_th("ชั่วโมง"), Err(ParseError::InvalidNumber { position: 0 }));
+        assert_eq!(try_duration_from_th("1:30 x"), Err(ParseError::TrailingCharacters { position: 5 }));
+    }
+}
@@ -0,0 +1,159 @@
+use std::fmt;
+
+use time::{Month, Weekday};
+
+/// why a date or time text cannot be parsed<br>
+/// `position` is byte index in the parsed text
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseError {
+    /// nothing to parse
+    Empty,
+    /// unexpected character between numbers, ex. `30/01x/2568`
+    BadSeparator { position: usize, found: char },
+    /// number of parts, ex. `30/01` has 2 of 3
+    WrongTokenCount { expected: usize, found: usize },
+    /// number of digits without separator, ex. `30012` has 5 of 6
+    MissingDigits { expected: usize, found: usize },
+    /// part is not a number, ex. `30//2568`
+    InvalidNumber { position: usize },
+    /// month is not 1-12
+    MonthOutOfRange { position: usize, month: u32 },
+    /// day does not exist in month, `year` is Common era
+    DayOutOfRange {
+        position: usize,
+        day: u32,
+        month: Month,
+        year: i32,
+    },
+    /// hour is not 0-23
+    HourOutOfRange { position: usize, hour: u32 },
+    /// minute is not 0-59
+    MinuteOutOfRange { position: usize, minute: u32 },
+    /// second is not 0-59
+    SecondOutOfRange { position: usize, second: u32 },
+    /// 3 digits year can be neither short year nor full year, ex. `256`
+    AmbiguousYear { position: usize, year: i32 },
+    /// text is not a month name
+    UnknownMonth { position: usize },
+    /// text is not a weekday name
+    UnknownWeekday { position: usize },
+    /// weekday prefix does not match the date
+    WeekdayMismatch {
+        position: usize,
+        found: Weekday,
+        expected: Weekday,
+    },
+    /// text is not a duration unit, ex. `1 ชั่ว`
+    UnknownUnit { position: usize },
+    /// unparsed text after a complete date or time
+    TrailingCharacters { position: usize },
+    /// unknown or unsupported part of a recurrence rule, ex. `BYMONTH=8`
+    InvalidRule { position: usize },
+    /// line of `.ics` text is not a property or components are not closed
+    InvalidCalendar { position: usize },
+}
+
+impl ParseError {
+    /// byte index of the error, `None` when the whole text is wrong
+    pub fn position(&self) -> Option<usize> {
+        match self {
+            Self::Empty | Self::WrongTokenCount { .. } | Self::MissingDigits { .. } => None,
+            Self::BadSeparator { position, .. }
+            | Self::InvalidNumber { position }
+            | Self::MonthOutOfRange { position, .. }
+            | Self::DayOutOfRange { position, .. }
+            | Self::HourOutOfRange { position, .. }
+            | Self::MinuteOutOfRange { position, .. }
+            | Self::SecondOutOfRange { position, .. }
+            | Self::AmbiguousYear { position, .. }
+            | Self::UnknownMonth { position }
+            | Self::UnknownWeekday { position }
+            | Self::WeekdayMismatch { position, .. }
+            | Self::UnknownUnit { position }
+            | Self::TrailingCharacters { position }
+            | Self::InvalidRule { position }
+            | Self::InvalidCalendar { position } => Some(*position),
+        }
+    }
+
+    /// shift `position` when the parsed text is a part of a longer text
+    pub fn offset(mut self, by: usize) -> Self {
+        match &mut self {
+            Self::Empty | Self::WrongTokenCount { .. } | Self::MissingDigits { .. } => {}
+            Self::BadSeparator { position, .. }
+            | Self::InvalidNumber { position }
+            | Self::MonthOutOfRange { position, .. }
+            | Self::DayOutOfRange { position, .. }
+            | Self::HourOutOfRange { position, .. }
+            | Self::MinuteOutOfRange { position, .. }
+            | Self::SecondOutOfRange { position, .. }
+            | Self::AmbiguousYear { position, .. }
+            | Self::UnknownMonth { position }
+            | Self::UnknownWeekday { position }
+            | Self::WeekdayMismatch { position, .. }
+            | Self::UnknownUnit { position }
+            | Self::TrailingCharacters { position }
+            | Self::InvalidRule { position }
+            | Self::InvalidCalendar { position } => *position += by,
+        }
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "empty text"),
+            Self::BadSeparator { position, found } => {
+                write!(f, "unexpected '{found}' at {position}")
+            }
+            Self::WrongTokenCount { expected, found } => {
+                write!(f, "expected {expected} parts, found {found}")
+            }
+            Self::MissingDigits { expected, found } => {
+                write!(f, "expected {expected} digits, found {found}")
+            }
+            Self::InvalidNumber { position } => write!(f, "invalid number at {position}"),
+            Self::MonthOutOfRange { position, month } => {
+                write!(f, "month {month} out of range at {position}")
+            }
+            Self::DayOutOfRange {
+                position,
+                day,
+                month,
+                year,
+            } => {
+                write!(f, "day {day} out of range for {month} {year} at {position}")
+            }
+            Self::HourOutOfRange { position, hour } => {
+                write!(f, "hour {hour} out of range at {position}")
+            }
+            Self::MinuteOutOfRange { position, minute } => {
+                write!(f, "minute {minute} out of range at {position}")
+            }
+            Self::SecondOutOfRange { position, second } => {
+                write!(f, "second {second} out of range at {position}")
+            }
+            Self::AmbiguousYear { position, year } => {
+                write!(f, "ambiguous year {year} at {position}")
+            }
+            Self::UnknownMonth { position } => write!(f, "unknown month at {position}"),
+            Self::UnknownWeekday { position } => write!(f, "unknown weekday at {position}"),
+            Self::WeekdayMismatch {
+                position,
+                found,
+                expected,
+            } => {
+                write!(f, "weekday {found} should be {expected} at {position}")
+            }
+            Self::UnknownUnit { position } => write!(f, "unknown unit at {position}"),
+            Self::TrailingCharacters { position } => {
+                write!(f, "unexpected text at {position}")
+            }
+            Self::InvalidRule { position } => write!(f, "invalid recurrence rule at {position}"),
+            Self::InvalidCalendar { position } => write!(f, "invalid calendar at {position}"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
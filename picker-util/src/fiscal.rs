@@ -0,0 +1,82 @@
+use time::{Date, Month};
+
+use crate::add_months;
+
+/// Thai fiscal year of date in Buddhist era, `1 ต.ค. 2567` - `30 ก.ย. 2568` is `2568`
+pub fn fiscal_year(date: &Date) -> i32 {
+    if date.month() >= Month::October {
+        date.year() + 544
+    } else {
+        date.year() + 543
+    }
+}
+
+/// fiscal quarter of date, 1 is `ต.ค.` - `ธ.ค.`
+pub fn fiscal_quarter(date: &Date) -> u8 {
+    (date.month() as u8 + 2) % 12 / 3 + 1
+}
+
+/// first and last date of Buddhist era fiscal year
+pub fn fiscal_year_range(year: i32) -> Option<(Date, Date)> {
+    let start = Date::from_calendar_date(year - 544, Month::October, 1).ok()?;
+    let end = Date::from_calendar_date(year - 543, Month::September, 30).ok()?;
+    Some((start, end))
+}
+
+/// first and last date of quarter 1-4 in Buddhist era fiscal year, `None` if quarter is not 1-4
+pub fn fiscal_quarter_range(year: i32, quarter: u8) -> Option<(Date, Date)> {
+    if !(1..=4).contains(&quarter) {
+        return None;
+    }
+    let (year_start, _) = fiscal_year_range(year)?;
+    let start = add_months(&year_start, (quarter as i64 - 1) * 3)?;
+    let end = add_months(&start, 3)?.previous_day()?;
+    Some((start, end))
+}
+
+/// `ปีงบประมาณ 2568`
+pub fn fiscal_year_th(year: i32) -> String {
+    format!("ปีงบประมาณ {year}")
+}
+
+/// `ปีงบประมาณ 2568 ไตรมาส 2`
+pub fn fiscal_quarter_th(year: i32, quarter: u8) -> String {
+    format!("ปีงบประมาณ {year} ไตรมาส {quarter}")
+}
+
+/// `ปีงบประมาณ 2568 ไตรมาส 2` of date
+pub fn fiscal_date_th(date: &Date) -> String {
+    fiscal_quarter_th(fiscal_year(date), fiscal_quarter(date))
+}
+
+#[cfg(test)]
+#[rustfmt::skip]
+pub mod tests {
+
+    use super::*;
+    use time::macros::date;
+
+    #[test]
+    fn test_fiscal_year() {
+        assert_eq!(fiscal_year(&date!(2024-09-30)), 2567);
+        assert_eq!(fiscal_year(&date!(2024-10-01)), 2568);
+        assert_eq!(fiscal_year(&date!(2025-09-30)), 2568);
+        assert_eq!(fiscal_quarter(&date!(2024-10-01)), 1);
+        assert_eq!(fiscal_quarter(&date!(2024-12-31)), 1);
+        assert_eq!(fiscal_quarter(&date!(2025-01-01)), 2);
+        assert_eq!(fiscal_quarter(&date!(2025-06-30)), 3);
+        assert_eq!(fiscal_quarter(&date!(2025-09-30)), 4);
+        assert_eq!(fiscal_date_th(&date!(2025-02-12)), String::from("ปีงบประมาณ 2568 ไตรมาส 2"));
+        assert_eq!(fiscal_year_th(2568), String::from("ปีงบประมาณ 2568"));
+    }
+
+    #[test]
+    fn test_fiscal_range() {
+        assert_eq!(fiscal_year_range(2568), Some((date!(2024-10-01), date!(2025-09-30))));
+        assert_eq!(fiscal_quarter_range(2568, 1), Some((date!(2024-10-01), date!(2024-12-31))));
+        assert_eq!(fiscal_quarter_range(2568, 2), Some((date!(2025-01-01), date!(2025-03-31))));
+        assert_eq!(fiscal_quarter_range(2568, 3), Some((date!(2025-04-01), date!(2025-06-30))));
+        assert_eq!(fiscal_quarter_range(2568, 4), Some((date!(2025-07-01), date!(2025-09-30))));
+        assert_eq!(fiscal_quarter_range(2568, 5), None);
+    }
+}
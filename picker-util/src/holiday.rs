@@ -0,0 +1,232 @@
+use serde_derive::{Deserialize, Serialize};
+use std::{cell::RefCell, collections::BTreeMap, ops::RangeInclusive};
+use time::{Date, Month, Weekday};
+
+/// one day off, `date` is `YYYY-MM-DD` in JSON
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Holiday {
+    pub date: Date,
+    pub name: String,
+}
+
+/// Thai public holidays on the same date every year
+const FIXED_HOLIDAYS: [(Month, u8, &str); 15] = [
+    (Month::January, 1, "วันขึ้นปีใหม่"),
+    (Month::April, 6, "วันจักรี"),
+    (Month::April, 13, "วันสงกรานต์"),
+    (Month::April, 14, "วันสงกรานต์"),
+    (Month::April, 15, "วันสงกรานต์"),
+    (Month::May, 1, "วันแรงงานแห่งชาติ"),
+    (Month::May, 4, "วันฉัตรมงคล"),
+    (Month::June, 3, "วันเฉลิมพระชนมพรรษาสมเด็จพระราชินี"),
+    (Month::July, 28, "วันเฉลิมพระชนมพรรษาพระบาทสมเด็จพระเจ้าอยู่หัว"),
+    (Month::August, 12, "วันแม่แห่งชาติ"),
+    (Month::October, 13, "วันนวมินทรมหาราช"),
+    (Month::October, 23, "วันปิยมหาราช"),
+    (Month::December, 5, "วันพ่อแห่งชาติ"),
+    (Month::December, 10, "วันรัฐธรรมนูญ"),
+    (Month::December, 31, "วันสิ้นปี"),
+];
+
+/// lunar holidays announced by the government, update yearly or load your own with `HolidayCalendar::from_json()`
+pub const LUNAR_HOLIDAYS_JSON: &str = r#"[
+    {"date": "2024-02-24", "name": "วันมาฆบูชา"},
+    {"date": "2024-05-22", "name": "วันวิสาขบูชา"},
+    {"date": "2024-07-20", "name": "วันอาสาฬหบูชา"},
+    {"date": "2024-07-21", "name": "วันเข้าพรรษา"},
+    {"date": "2025-02-12", "name": "วันมาฆบูชา"},
+    {"date": "2025-05-11", "name": "วันวิสาขบูชา"},
+    {"date": "2025-07-10", "name": "วันอาสาฬหบูชา"},
+    {"date": "2025-07-11", "name": "วันเข้าพรรษา"},
+    {"date": "2026-03-03", "name": "วันมาฆบูชา"},
+    {"date": "2026-05-31", "name": "วันวิสาขบูชา"},
+    {"date": "2026-07-29", "name": "วันอาสาฬหบูชา"},
+    {"date": "2026-07-30", "name": "วันเข้าพรรษา"}
+]"#;
+
+/// Thai public holidays, fixed dates plus lunar table<br>
+/// holiday on Saturday or Sunday gets `วันหยุดชดเชย` on the next working day<br>
+/// holidays of a year are built once on first lookup, a picker asks for every day cell
+#[derive(Clone, Debug)]
+pub struct HolidayCalendar {
+    lunar: Vec<Holiday>,
+    years: RefCell<BTreeMap<i32, Vec<Holiday>>>,
+}
+
+impl PartialEq for HolidayCalendar {
+    fn eq(&self, other: &Self) -> bool {
+        self.lunar == other.lunar
+    }
+}
+
+impl Eq for HolidayCalendar {}
+
+impl Default for HolidayCalendar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HolidayCalendar {
+    /// fixed holidays with bundled `LUNAR_HOLIDAYS_JSON`
+    pub fn new() -> Self {
+        Self::from_json(LUNAR_HOLIDAYS_JSON).unwrap_or_else(|_| Self::fixed())
+    }
+
+    /// fixed holidays only
+    pub fn fixed() -> Self {
+        Self::with_lunar(Vec::new())
+    }
+
+    /// fixed holidays with lunar table `[{"date": "2025-02-12", "name": "วันมาฆบูชา"}]`
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        let lunar = serde_json::from_str(json)?;
+        Ok(Self::with_lunar(lunar))
+    }
+
+    fn with_lunar(lunar: Vec<Holiday>) -> Self {
+        Self {
+            lunar,
+            years: RefCell::new(BTreeMap::new()),
+        }
+    }
+
+    /// add lunar or special holidays, ex. from the server
+    pub fn with_holidays<I: IntoIterator<Item = Holiday>>(mut self, holidays: I) -> Self {
+        self.lunar.extend(holidays);
+        self.years.get_mut().clear();
+        self
+    }
+
+    /// first to last Common era year of the lunar table, other years have no lunar holidays<br>
+    /// `None` for fixed holidays only
+    pub fn lunar_years(&self) -> Option<RangeInclusive<i32>> {
+        let first = self.lunar.iter().map(|h| h.date.year()).min()?;
+        let last = self.lunar.iter().map(|h| h.date.year()).max()?;
+        Some(first..=last)
+    }
+
+    /// all holidays of Common era `year` including substitution days, sorted by date
+    pub fn holidays_in_year(&self, year: i32) -> Vec<Holiday> {
+        self.with_year(year, <[Holiday]>::to_vec)
+    }
+
+    /// `f` of cached holidays of `year`
+    fn with_year<T>(&self, year: i32, f: impl FnOnce(&[Holiday]) -> T) -> T {
+        let mut years = self.years.borrow_mut();
+        f(years.entry(year).or_insert_with(|| self.build_year(year)))
+    }
+
+    fn build_year(&self, year: i32) -> Vec<Holiday> {
+        // previous year, `31 ธ.ค.` on weekend will take `2 ม.ค.`
+        let years = [year.checked_sub(1), Some(year)];
+        let mut holidays = years
+            .iter()
+            .flatten()
+            .flat_map(|y| {
+                FIXED_HOLIDAYS.iter().filter_map(move |(mm, dd, name)| {
+                    Date::from_calendar_date(*y, *mm, *dd)
+                        .ok()
+                        .map(|date| Holiday {
+                            date,
+                            name: String::from(*name),
+                        })
+                })
+            })
+            .chain(
+                self.lunar
+                    .iter()
+                    .filter(|h| years.contains(&Some(h.date.year())))
+                    .cloned(),
+            )
+            .collect::<Vec<Holiday>>();
+        holidays.sort_by_key(|h| h.date);
+
+        let mut substitutes: Vec<Holiday> = Vec::new();
+        for holiday in holidays.iter().filter(|h| is_weekend(&h.date)) {
+            let mut date = holiday.date;
+            while let Some(next) = date.next_day() {
+                date = next;
+                if !is_weekend(&date)
+                    && !holidays.iter().any(|h| h.date == date)
+                    && !substitutes.iter().any(|h| h.date == date)
+                {
+                    substitutes.push(Holiday {
+                        date,
+                        name: ["วันหยุดชดเชย", &holiday.name].concat(),
+                    });
+                    break;
+                }
+            }
+        }
+
+        holidays.extend(substitutes);
+        holidays.retain(|h| h.date.year() == year);
+        holidays.sort_by_key(|h| h.date);
+        holidays
+    }
+
+    pub fn is_holiday(&self, date: &Date) -> bool {
+        self.holiday_name(date).is_some()
+    }
+
+    /// `None` if not a holiday, the first name if many holidays are on the same date
+    pub fn holiday_name(&self, date: &Date) -> Option<String> {
+        self.with_year(date.year(), |holidays| {
+            holidays
+                .iter()
+                .find(|h| h.date == *date)
+                .map(|h| h.name.clone())
+        })
+    }
+}
+
+fn is_weekend(date: &Date) -> bool {
+    matches!(date.weekday(), Weekday::Saturday | Weekday::Sunday)
+}
+
+#[cfg(test)]
+#[rustfmt::skip]
+pub mod tests {
+
+    use super::*;
+    use time::macros::date;
+
+    #[test]
+    fn test_holiday_name() {
+        let calendar = HolidayCalendar::new();
+        assert_eq!(calendar.holiday_name(&date!(2025-12-05)), Some(String::from("วันพ่อแห่งชาติ")));
+        assert_eq!(calendar.holiday_name(&date!(2025-04-14)), Some(String::from("วันสงกรานต์")));
+        assert_eq!(calendar.holiday_name(&date!(2025-02-12)), Some(String::from("วันมาฆบูชา")));
+        assert!(!calendar.is_holiday(&date!(2025-02-13)));
+        assert!(!HolidayCalendar::fixed().is_holiday(&date!(2025-02-12)));
+        assert_eq!(calendar.lunar_years(), Some(2024..=2026));
+        assert_eq!(HolidayCalendar::fixed().lunar_years(), None);
+        assert_eq!(calendar.holidays_in_year(i32::MIN), vec![]);
+        // วันแม่ 2023 is Saturday
+        assert_eq!(calendar.holiday_name(&date!(2023-08-14)), Some(String::from("วันหยุดชดเชยวันแม่แห่งชาติ")));
+        // สงกรานต์ 2024 is Saturday to Monday
+        assert_eq!(calendar.holiday_name(&date!(2024-04-16)), Some(String::from("วันหยุดชดเชยวันสงกรานต์")));
+        // 31 ธ.ค. 2565 and 1 ม.ค. 2566 are weekend
+        assert_eq!(calendar.holiday_name(&date!(2023-01-02)), Some(String::from("วันหยุดชดเชยวันสิ้นปี")));
+        assert_eq!(calendar.holiday_name(&date!(2023-01-03)), Some(String::from("วันหยุดชดเชยวันขึ้นปีใหม่")));
+    }
+
+    #[test]
+    fn test_from_json() {
+        let calendar = HolidayCalendar::from_json(r#"[{"date": "2027-02-20", "name": "วันมาฆบูชา"}]"#).unwrap();
+        assert_eq!(calendar.holiday_name(&date!(2027-02-20)), Some(String::from("วันมาฆบูชา")));
+        assert_eq!(calendar.holiday_name(&date!(2027-02-22)), Some(String::from("วันหยุดชดเชยวันมาฆบูชา")));
+        assert!(HolidayCalendar::from_json(r#"[{"date": "20/02/2570"}]"#).is_err());
+        let calendar = HolidayCalendar::fixed().with_holidays([Holiday { date: date!(2025-06-02), name: String::from("วันหยุดพิเศษ") }]);
+        assert!(calendar.is_holiday(&date!(2025-06-02)));
+        assert_eq!(calendar.holidays_in_year(2025).len(), 19);
+        // cached year is rebuilt with new holidays
+        let calendar = calendar.with_holidays([Holiday { date: date!(2025-06-03), name: String::from("วันหยุดพิเศษ") }]);
+        assert_eq!(calendar.holidays_in_year(2025).len(), 20);
+        // cache is not compared
+        let cached = HolidayCalendar::new();
+        assert!(cached.is_holiday(&date!(2025-12-05)));
+        assert_eq!(cached, HolidayCalendar::new());
+    }
+}
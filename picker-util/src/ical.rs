@@ -0,0 +1,593 @@
+//! iCalendar `.ics` reader and writer, `VEVENT` with `UID`, `SUMMARY`, `DTSTART`, `DTEND`,
+//! `RRULE` and `EXDATE`
+//!
+//! ```ignore
+//! let holidays = Calendar::from_holidays(&HolidayCalendar::new().holidays_in_year(2025));
+//! let ics = holidays.to_string();
+//! let feed: Calendar = ics.parse()?;
+//! HolidayCalendar::fixed().with_holidays(feed.holidays_in(&range));
+//! ```
+//! timed events are written with `TZID=Asia/Bangkok`, other `TZID` are read as default offset
+
+use std::{fmt, str::FromStr};
+
+use time::{Date, Duration, PrimitiveDateTime, Time, UtcOffset, macros::offset};
+
+use crate::{
+    ParseError,
+    clock::{Clock, default_clock},
+    default_offset,
+    holiday::Holiday,
+    range::DateRange,
+    recurrence::{Recurrence, RecurrenceRule, basic_datetime, parse_basic},
+};
+
+pub const BANGKOK_TZID: &str = "Asia/Bangkok";
+const BANGKOK_OFFSET: UtcOffset = offset!(+7);
+
+const VTIMEZONE: [&str; 9] = [
+    "BEGIN:VTIMEZONE",
+    "TZID:Asia/Bangkok",
+    "BEGIN:STANDARD",
+    "DTSTART:19700101T000000",
+    "TZOFFSETFROM:+0700",
+    "TZOFFSETTO:+0700",
+    "TZNAME:ICT",
+    "END:STANDARD",
+    "END:VTIMEZONE",
+];
+
+/// content line limit in octets, longer lines are folded
+const LINE_OCTETS: usize = 75;
+
+/// when an event happens, all at default offset
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EventTime {
+    /// inclusive days, written as `DTSTART;VALUE=DATE` and the next day as `DTEND`
+    AllDay(DateRange),
+    Timed {
+        start: PrimitiveDateTime,
+        end: Option<PrimitiveDateTime>,
+    },
+}
+
+/// one `VEVENT`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Event {
+    pub uid: String,
+    pub summary: String,
+    pub time: EventTime,
+    pub rule: Option<RecurrenceRule>,
+    /// cancelled occurrences, midnight for all-day events
+    pub exdates: Vec<PrimitiveDateTime>,
+}
+
+impl Event {
+    pub fn all_day(uid: &str, summary: &str, days: DateRange) -> Self {
+        Self::with_time(uid, summary, EventTime::AllDay(days))
+    }
+
+    pub fn timed(
+        uid: &str,
+        summary: &str,
+        start: PrimitiveDateTime,
+        end: Option<PrimitiveDateTime>,
+    ) -> Self {
+        Self::with_time(uid, summary, EventTime::Timed { start, end })
+    }
+
+    fn with_time(uid: &str, summary: &str, time: EventTime) -> Self {
+        Self {
+            uid: String::from(uid),
+            summary: String::from(summary),
+            time,
+            rule: None,
+            exdates: Vec::new(),
+        }
+    }
+
+    /// `DTSTART`, midnight for all-day events
+    pub fn start(&self) -> PrimitiveDateTime {
+        match &self.time {
+            EventTime::AllDay(days) => days.start().midnight(),
+            EventTime::Timed { start, .. } => *start,
+        }
+    }
+
+    pub fn is_all_day(&self) -> bool {
+        matches!(self.time, EventTime::AllDay(_))
+    }
+
+    /// `None` if not recurring
+    pub fn recurrence(&self) -> Option<Recurrence> {
+        self.rule.as_ref().map(|rule| Recurrence {
+            start: self.start(),
+            rule: rule.clone(),
+            exdates: self.exdates.clone(),
+        })
+    }
+
+    /// starts of the event on days of `range`
+    pub fn occurrences(&self, range: &DateRange) -> Vec<PrimitiveDateTime> {
+        match self.recurrence() {
+            Some(recurrence) => recurrence.occurrences(range),
+            None if range.contains(&self.start().date()) => vec![self.start()],
+            None => Vec::new(),
+        }
+    }
+}
+
+/// one `VCALENDAR`, `Display` writes `.ics` text with `DTSTAMP` from `default_clock()`
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Calendar {
+    pub events: Vec<Event>,
+}
+
+impl Calendar {
+    pub fn new(events: Vec<Event>) -> Self {
+        Self { events }
+    }
+
+    /// one all-day event per holiday, ex. from `HolidayCalendar::holidays_in_year()`
+    pub fn from_holidays(holidays: &[Holiday]) -> Self {
+        let events = holidays
+            .iter()
+            .filter_map(|holiday| {
+                let days = DateRange::new(holiday.date, holiday.date)?;
+                let uid = format!("{}@picker-util", holiday.date);
+                Some(Event::all_day(&uid, &holiday.name, days))
+            })
+            .collect();
+        Self { events }
+    }
+
+    /// days of all-day events in `range` named by `SUMMARY`, for `HolidayCalendar::with_holidays()`
+    pub fn holidays_in(&self, range: &DateRange) -> Vec<Holiday> {
+        let mut holidays = self
+            .events
+            .iter()
+            .filter_map(|event| match &event.time {
+                EventTime::AllDay(days) => Some((event, days.len_days())),
+                EventTime::Timed { .. } => None,
+            })
+            .flat_map(|(event, length)| {
+                // a multi-day event may start before `range`
+                let from = range.start().checked_sub(Duration::days(length - 1));
+                let search = from.and_then(|from| DateRange::new(from, range.end()));
+                search
+                    .map(|search| event.occurrences(&search))
+                    .unwrap_or_default()
+                    .into_iter()
+                    .flat_map(move |start| {
+                        std::iter::successors(Some(start.date()), |date| date.next_day())
+                            .take(length as usize)
+                    })
+                    .filter(|date| range.contains(date))
+                    .map(|date| Holiday {
+                        date,
+                        name: event.summary.clone(),
+                    })
+            })
+            .collect::<Vec<Holiday>>();
+        holidays.sort_by_key(|holiday| holiday.date);
+        holidays
+    }
+}
+
+/// `.ics` text with `DTSTAMP` from `clock`, lines are folded at 75 octets and end with `\r\n`
+pub fn write_ics(calendar: &Calendar, clock: &dyn Clock) -> String {
+    let stamp = basic_datetime(&clock.now_at(UtcOffset::UTC)) + "Z";
+    let mut lines = vec![
+        String::from("BEGIN:VCALENDAR"),
+        String::from("VERSION:2.0"),
+        String::from("PRODID:-//picker-util//TH"),
+        String::from("CALSCALE:GREGORIAN"),
+    ];
+    if calendar.events.iter().any(|event| !event.is_all_day()) {
+        lines.extend(VTIMEZONE.map(String::from));
+    }
+    for event in &calendar.events {
+        lines.push(String::from("BEGIN:VEVENT"));
+        lines.push(["UID:", &escape(&event.uid)].concat());
+        lines.push(["DTSTAMP:", &stamp].concat());
+        match &event.time {
+            EventTime::AllDay(days) => {
+                lines.push(["DTSTART;VALUE=DATE:", &basic_date(&days.start())].concat());
+                if let Some(end) = days.end().next_day() {
+                    lines.push(["DTEND;VALUE=DATE:", &basic_date(&end)].concat());
+                }
+            }
+            EventTime::Timed { start, end } => {
+                lines.push(["DTSTART;TZID=Asia/Bangkok:", &to_bangkok(start)].concat());
+                if let Some(end) = end {
+                    lines.push(["DTEND;TZID=Asia/Bangkok:", &to_bangkok(end)].concat());
+                }
+            }
+        }
+        lines.push(["SUMMARY:", &escape(&event.summary)].concat());
+        if let Some(rule) = &event.rule {
+            lines.push(["RRULE:", &rule_ics(rule, event.is_all_day())].concat());
+        }
+        if !event.exdates.is_empty() {
+            let dates = event
+                .exdates
+                .iter()
+                .map(|date_time| {
+                    if event.is_all_day() {
+                        basic_date(&date_time.date())
+                    } else {
+                        to_bangkok(date_time)
+                    }
+                })
+                .collect::<Vec<String>>()
+                .join(",");
+            let name = if event.is_all_day() {
+                "EXDATE;VALUE=DATE:"
+            } else {
+                "EXDATE;TZID=Asia/Bangkok:"
+            };
+            lines.push([name, &dates].concat());
+        }
+        lines.push(String::from("END:VEVENT"));
+    }
+    lines.push(String::from("END:VCALENDAR"));
+    lines.iter().map(|line| fold(line) + "\r\n").collect()
+}
+
+impl fmt::Display for Calendar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&write_ics(self, default_clock()))
+    }
+}
+
+impl FromStr for Calendar {
+    type Err = ParseError;
+
+    /// every `VEVENT` in the text, unknown properties and components are skipped<br>
+    /// `position` of `ParseError::InvalidCalendar` is the start of the wrong line
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        if text.trim().is_empty() {
+            return Err(ParseError::Empty);
+        }
+        let mut events = Vec::new();
+        let mut components: Vec<String> = Vec::new();
+        let mut properties: Vec<(usize, Property)> = Vec::new();
+        for (position, line) in unfold(text) {
+            let property =
+                Property::parse(&line).ok_or(ParseError::InvalidCalendar { position })?;
+            match property.name.as_str() {
+                "BEGIN" => {
+                    if property.value.eq_ignore_ascii_case("VEVENT") {
+                        properties.clear();
+                    }
+                    components.push(property.value.to_ascii_uppercase());
+                }
+                "END" => {
+                    let end = property.value.to_ascii_uppercase();
+                    if components.pop().as_ref() != Some(&end) {
+                        return Err(ParseError::InvalidCalendar { position });
+                    }
+                    if end == "VEVENT" {
+                        events.push(event_from(&properties, position)?);
+                    }
+                }
+                _ if components.last().is_some_and(|name| name == "VEVENT") => {
+                    properties.push((position, property));
+                }
+                _ => {}
+            }
+        }
+        if components.is_empty() {
+            Ok(Self { events })
+        } else {
+            Err(ParseError::InvalidCalendar {
+                position: text.len(),
+            })
+        }
+    }
+}
+
+/// `NAME;PARAM=VALUE:value` of an unfolded line, name and parameter names are uppercase
+struct Property {
+    name: String,
+    params: Vec<(String, String)>,
+    value: String,
+    /// bytes before value in the line
+    value_offset: usize,
+}
+
+impl Property {
+    fn parse(line: &str) -> Option<Self> {
+        // `:` in quoted parameter value is not the separator
+        let mut quoted = false;
+        let colon = line.char_indices().find_map(|(index, c)| match c {
+            '"' => {
+                quoted = !quoted;
+                None
+            }
+            ':' if !quoted => Some(index),
+            _ => None,
+        })?;
+        let mut parts = line[..colon].split(';');
+        let name = parts.next()?.trim().to_ascii_uppercase();
+        if name.is_empty() {
+            return None;
+        }
+        let params = parts
+            .filter_map(|param| param.split_once('='))
+            .map(|(key, value)| {
+                (
+                    key.trim().to_ascii_uppercase(),
+                    value.trim_matches('"').to_string(),
+                )
+            })
+            .collect();
+        Some(Self {
+            name,
+            params,
+            value: line[colon + 1..].to_string(),
+            value_offset: colon + 1,
+        })
+    }
+
+    fn param(&self, key: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(name, _)| name == key)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// dates or datetimes of the value at default offset, `TZID=Asia/Bangkok` is converted
+    fn date_times(&self, position: usize) -> Result<Vec<(Date, Option<Time>)>, ParseError> {
+        let bangkok = self.param("TZID") == Some(BANGKOK_TZID);
+        let mut position = position + self.value_offset;
+        self.value
+            .split(',')
+            .map(|item| {
+                let parsed = parse_basic(item.trim(), position);
+                position += item.len() + 1;
+                let (date, time) = parsed?;
+                match time {
+                    Some(time) if bangkok && !item.trim().ends_with(['Z', 'z']) => {
+                        let local = PrimitiveDateTime::new(date, time)
+                            .assume_offset(BANGKOK_OFFSET)
+                            .to_offset(default_offset());
+                        Ok((local.date(), Some(local.time())))
+                    }
+                    _ => Ok((date, time)),
+                }
+            })
+            .collect()
+    }
+}
+
+fn event_from(properties: &[(usize, Property)], end: usize) -> Result<Event, ParseError> {
+    let find = |name: &str| {
+        properties
+            .iter()
+            .find(|(_, property)| property.name == name)
+    };
+    let (position, dtstart) =
+        find("DTSTART").ok_or(ParseError::InvalidCalendar { position: end })?;
+    let (start_date, start_time) =
+        *dtstart
+            .date_times(*position)?
+            .first()
+            .ok_or(ParseError::InvalidCalendar {
+                position: *position,
+            })?;
+    let end = match find("DTEND") {
+        Some((position, dtend)) => dtend.date_times(*position)?.first().copied(),
+        None => None,
+    };
+    let time = match start_time {
+        None => {
+            // DTEND of all-day event is exclusive
+            let end = end.map_or(Some(start_date), |(date, _)| date.previous_day());
+            let days = end
+                .and_then(|end| DateRange::new(start_date, end.max(start_date)))
+                .ok_or(ParseError::InvalidCalendar {
+                    position: *position,
+                })?;
+            EventTime::AllDay(days)
+        }
+        Some(time) => EventTime::Timed {
+            start: PrimitiveDateTime::new(start_date, time),
+            end: end
+                .map(|(date, time)| PrimitiveDateTime::new(date, time.unwrap_or(Time::MIDNIGHT))),
+        },
+    };
+    let start_time = start_time.unwrap_or(Time::MIDNIGHT);
+    let rule = match find("RRULE") {
+        Some((position, rrule)) => Some(
+            rrule
+                .value
+                .parse::<RecurrenceRule>()
+                .map_err(|e| e.offset(position + rrule.value_offset))?,
+        ),
+        None => None,
+    };
+    let mut exdates = Vec::new();
+    for (position, exdate) in properties
+        .iter()
+        .filter(|(_, property)| property.name == "EXDATE")
+    {
+        exdates.extend(
+            exdate
+                .date_times(*position)?
+                .into_iter()
+                .map(|(date, time)| PrimitiveDateTime::new(date, time.unwrap_or(start_time))),
+        );
+    }
+    let text = |name: &str| {
+        find(name)
+            .map(|(_, property)| unescape(&property.value))
+            .unwrap_or_default()
+    };
+    Ok(Event {
+        uid: text("UID"),
+        summary: text("SUMMARY"),
+        time,
+        rule,
+        exdates,
+    })
+}
+
+/// `20250812`
+fn basic_date(date: &Date) -> String {
+    format!(
+        "{:04}{:02}{:02}",
+        date.year(),
+        date.month() as u8,
+        date.day()
+    )
+}
+
+/// default offset to `Asia/Bangkok` local time, `20250805T083000`
+fn to_bangkok(date_time: &PrimitiveDateTime) -> String {
+    let local = date_time
+        .assume_offset(default_offset())
+        .to_offset(BANGKOK_OFFSET);
+    basic_datetime(&PrimitiveDateTime::new(local.date(), local.time()))
+}
+
+/// `UNTIL` is a date for all-day events and UTC for timed events
+fn rule_ics(rule: &RecurrenceRule, all_day: bool) -> String {
+    let Some(until) = rule.until else {
+        return rule.to_string();
+    };
+    let rule = RecurrenceRule {
+        until: None,
+        ..rule.clone()
+    };
+    let until = if all_day {
+        basic_date(&until.date())
+    } else {
+        let utc = until
+            .assume_offset(default_offset())
+            .to_offset(UtcOffset::UTC);
+        basic_datetime(&PrimitiveDateTime::new(utc.date(), utc.time())) + "Z"
+    };
+    format!("{rule};UNTIL={until}")
+}
+
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+fn unescape(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some('n' | 'N') => result.push('\n'),
+                Some(c) => result.push(c),
+                None => result.push('\\'),
+            },
+            c => result.push(c),
+        }
+    }
+    result
+}
+
+/// split at char boundaries into lines of 75 octets, continued lines start with a space
+fn fold(line: &str) -> String {
+    let mut result = String::with_capacity(line.len() + line.len() / LINE_OCTETS * 3);
+    let mut octets = 0;
+    for c in line.chars() {
+        if octets + c.len_utf8() > LINE_OCTETS {
+            result.push_str("\r\n ");
+            octets = 1;
+        }
+        result.push(c);
+        octets += c.len_utf8();
+    }
+    result
+}
+
+/// content lines with their byte position, a line starting with space or tab continues the previous line
+fn unfold(text: &str) -> Vec<(usize, String)> {
+    let mut lines: Vec<(usize, String)> = Vec::new();
+    let mut position = 0;
+    for line in text.split('\n') {
+        let line_position = position;
+        position += line.len() + 1;
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        match (line.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(rest), Some((_, last))) => last.push_str(rest),
+            _ if line.trim().is_empty() => {}
+            _ => lines.push((line_position, String::from(line))),
+        }
+    }
+    lines
+}
+
+#[cfg(test)]
+#[rustfmt::skip]
+pub mod tests {
+
+    use super::*;
+    use crate::{clock::FixedClock, recurrence::{ByDay, Frequency}};
+    use time::{Weekday, macros::{date, datetime}};
+
+    fn clinic() -> Calendar {
+        let mut session = Event::timed("session-1@kphis", "ตรวจคลินิกเบาหวาน, ห้อง 2", datetime!(2025-08-05 08:30), Some(datetime!(2025-08-05 12:00)));
+        session.rule = Some(RecurrenceRule { by_day: vec![ByDay::nth(2, Weekday::Tuesday)], until: Some(datetime!(2025-12-31 23:59:59)), ..RecurrenceRule::new(Frequency::Monthly) });
+        session.exdates.push(datetime!(2025-10-14 08:30));
+        let songkran = Event::all_day("songkran@kphis", "วันสงกรานต์", DateRange::new(date!(2025-04-13), date!(2025-04-15)).unwrap());
+        Calendar::new(vec![session, songkran])
+    }
+
+    #[test]
+    fn test_write_ics() {
+        let ics = write_ics(&clinic(), &FixedClock::at(datetime!(2025-08-01 09:00), offset!(+7)));
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"));
+        assert!(ics.ends_with("END:VEVENT\r\nEND:VCALENDAR\r\n"));
+        assert!(ics.contains("\r\nTZID:Asia/Bangkok\r\n"));
+        assert!(ics.contains("\r\nDTSTAMP:20250801T020000Z\r\n"));
+        assert!(ics.contains("\r\nDTSTART;TZID=Asia/Bangkok:20250805T083000\r\nDTEND;TZID=Asia/Bangkok:20250805T120000\r\n"));
+        assert!(ics.contains("\r\nRRULE:FREQ=MONTHLY;BYDAY=2TU;UNTIL=20251231T165959Z\r\n"));
+        assert!(ics.contains("\r\nEXDATE;TZID=Asia/Bangkok:20251014T083000\r\n"));
+        assert!(ics.contains("\r\nDTSTART;VALUE=DATE:20250413\r\nDTEND;VALUE=DATE:20250416\r\n"));
+        // Thai summary is folded at 75 octets, escaped comma
+        assert!(ics.contains("\r\nSUMMARY:ตรวจคลินิกเบาหวาน\\, ห้อง \r\n 2\r\n"));
+        assert!(ics.split("\r\n").all(|line| line.len() <= 75));
+    }
+
+    #[test]
+    fn test_read_ics() {
+        let calendar = clinic();
+        let ics = write_ics(&calendar, &FixedClock::at(datetime!(2025-08-01 09:00), offset!(+7)));
+        assert_eq!(ics.parse::<Calendar>(), Ok(calendar.clone()));
+        // UTC, floating and date only values, unknown components and properties
+        let ics = "BEGIN:VCALENDAR\nBEGIN:VEVENT\nUID:a\nDTSTART:20250805T013000Z\nLOCATION:OPD\nBEGIN:VALARM\nTRIGGER:-PT15M\nEND:VALARM\nEND:VEVENT\nBEGIN:VEVENT\nDTSTART;VALUE=DATE:20250812\nSUMMARY:วันแม่\n แห่งชาติ\nEND:VEVENT\nEND:VCALENDAR";
+        let calendar: Calendar = ics.parse().unwrap();
+        assert_eq!(calendar.events[0].time, EventTime::Timed { start: datetime!(2025-08-05 08:30), end: None });
+        assert_eq!(calendar.events[1].time, EventTime::AllDay(DateRange::new(date!(2025-08-12), date!(2025-08-12)).unwrap()));
+        assert_eq!(calendar.events[1].summary, "วันแม่แห่งชาติ");
+        // errors
+        assert_eq!("".parse::<Calendar>(), Err(ParseError::Empty));
+        assert_eq!("BEGIN:VCALENDAR\nBEGIN:VEVENT\nSUMMARY:x\nEND:VEVENT\nEND:VCALENDAR".parse::<Calendar>(), Err(ParseError::InvalidCalendar { position: 39 }));
+        assert_eq!("BEGIN:VCALENDAR\nBEGIN:VEVENT\nEND:VCALENDAR".parse::<Calendar>(), Err(ParseError::InvalidCalendar { position: 29 }));
+        assert_eq!("BEGIN:VCALENDAR\nBEGIN:VEVENT\nDTSTART:2025-08-05\nEND:VEVENT".parse::<Calendar>(), Err(ParseError::InvalidRule { position: 37 }));
+    }
+
+    #[test]
+    fn test_holidays_in() {
+        let august = DateRange::new(date!(2025-08-01), date!(2025-08-31)).unwrap();
+        let calendar = clinic();
+        assert_eq!(calendar.events[0].occurrences(&DateRange::new(date!(2025-08-01), date!(2025-10-31)).unwrap()), vec![datetime!(2025-08-12 08:30), datetime!(2025-09-09 08:30)]);
+        assert!(calendar.holidays_in(&august).is_empty());
+        let april = DateRange::new(date!(2025-04-14), date!(2025-04-30)).unwrap();
+        assert_eq!(calendar.holidays_in(&april).iter().map(|holiday| holiday.date).collect::<Vec<_>>(), vec![date!(2025-04-14), date!(2025-04-15)]);
+        // round trip with HolidayCalendar
+        let holidays = crate::holiday::HolidayCalendar::new().holidays_in_year(2025);
+        let feed: Calendar = Calendar::from_holidays(&holidays).to_string().parse().unwrap();
+        let year = DateRange::new(date!(2025-01-01), date!(2025-12-31)).unwrap();
+        assert_eq!(feed.holidays_in(&year), holidays);
+    }
+}
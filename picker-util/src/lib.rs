@@ -1,39 +1,148 @@
+pub mod age;
+pub mod business;
 pub mod class;
+pub mod clock;
+pub mod duration;
+pub mod error;
+pub mod fiscal;
+pub mod holiday;
+pub mod ical;
+pub mod locale;
+pub mod lunar;
+pub mod parse_th;
+pub mod pattern;
+pub mod range;
+pub mod reading;
+pub mod recurrence;
+pub mod relative;
+pub mod serde_fmt;
+pub mod week;
 
+#[cfg(feature = "wasm")]
+use clock::JsClock;
+#[cfg(feature = "native")]
+use clock::SystemClock;
+use clock::{Clock, default_clock};
+use std::sync::atomic::{AtomicI32, Ordering};
 use time::{
-    Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time, Weekday,
+    Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset, Weekday,
     format_description::well_known::Iso8601, macros::offset,
 };
 
+pub use error::ParseError;
+use locale::{Locale, Thai};
+#[cfg(feature = "ulid")]
+use ulid::Ulid;
+
+/// smallest time unit of js_string, pattern and pickers
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Precision {
+    /// `HH:mm`
+    #[default]
+    Minutes,
+    /// `HH:mm:ss`
+    Seconds,
+    /// `HH:mm:ss.SSS`
+    Millis,
+}
+
+impl Precision {
+    /// length of `time_pat_with()` text
+    pub fn time_len(&self) -> usize {
+        match self {
+            Self::Minutes => 5,
+            Self::Seconds => 8,
+            Self::Millis => 12,
+        }
+    }
+
+    /// `pattern::Pattern` of `time_pat_with()`, `HH:mm`, `HH:mm:ss` or `HH:mm:ss.SSS`
+    pub fn time_pattern(&self) -> &'static str {
+        match self {
+            Self::Minutes => "HH:mm",
+            Self::Seconds => "HH:mm:ss",
+            Self::Millis => "HH:mm:ss.SSS",
+        }
+    }
+}
+
+/// how to expand 2 digits Buddhist era year, `68` to `2568`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum YearPivot {
+    /// century of today, `99` typed in 2568 is 2599
+    #[default]
+    CurrentCentury,
+    /// fixed Buddhist era century, `Century(2400)` makes `99` 2499
+    Century(i32),
+    /// up to `future` years after today, the rest of 100 years before,
+    /// `Window { future: 20 }` typed in 2568 is 2489-2588
+    Window { future: i32 },
+    /// not after today, for birth dates, `99` typed in 2568 is 2499
+    Past,
+}
+
+impl YearPivot {
+    /// full Buddhist era year of 2 digits `short` year, `today` is Buddhist era year
+    pub fn expand(&self, short: i32, today: i32) -> i32 {
+        let year = (today / 100) * 100 + short;
+        match self {
+            Self::CurrentCentury => year,
+            Self::Century(century) => century + short,
+            Self::Window { future } => {
+                if year > today + future {
+                    year - 100
+                } else if year <= today + future - 100 {
+                    year + 100
+                } else {
+                    year
+                }
+            }
+            Self::Past => {
+                if year > today {
+                    year - 100
+                } else {
+                    year
+                }
+            }
+        }
+    }
+}
+
 pub trait JsTime {
-    fn js_string(&self) -> String;
+    /// `HH:mm` for `<input type="time">`
+    fn js_string(&self) -> String {
+        self.js_string_with(Precision::Minutes)
+    }
+    fn js_string_with(&self, precision: Precision) -> String;
 }
 
 impl JsTime for Time {
-    fn js_string(&self) -> String {
-        // HH:mm
-        format!("{:02}:{:02}", self.hour(), self.minute())
-        // // HH:mm:ss
-        // format!("{:02}:{:02}:{:02}",
-        //     self.hour(), self.minute(), self.second()
-        // )
-        // // HH:mm:ss:SSS
-        // format!("{:02}:{:02}:{:02}.{:03}",
-        // self.hour(), self.minute(), self.second(), self.nanosecond() / 1_000_000,
-        // )
+    fn js_string_with(&self, precision: Precision) -> String {
+        match precision {
+            // HH:mm
+            Precision::Minutes => format!("{:02}:{:02}", self.hour(), self.minute()),
+            // HH:mm:ss
+            Precision::Seconds => format!(
+                "{:02}:{:02}:{:02}",
+                self.hour(),
+                self.minute(),
+                self.second()
+            ),
+            // HH:mm:ss.SSS
+            Precision::Millis => format!(
+                "{:02}:{:02}:{:02}.{:03}",
+                self.hour(),
+                self.minute(),
+                self.second(),
+                self.millisecond()
+            ),
+        }
     }
 }
 
 impl JsTime for PrimitiveDateTime {
-    fn js_string(&self) -> String {
-        // HH:mm
-        format!("{}T{:02}:{:02}", self.date(), self.hour(), self.minute())
-        // // HH:mm:ss
-        // format!("{}T{:02}:{:02}:{:02}", self.date(), self.hour(), self.minute(), self.second())
-        // // HH:mm:ss:SSS
-        // format!("{} {:02}:{:02}:{:02}.{:03}",
-        // self.date(), self.hour(), self.minute(), self.second(), self.nanosecond() / 1_000_000,
-        // )
+    fn js_string_with(&self, precision: Precision) -> String {
+        format!("{}T{}", self.date(), self.time().js_string_with(precision))
     }
 }
 
@@ -41,21 +150,45 @@ impl JsTime for PrimitiveDateTime {
 //   Instant   //
 // ===== ===== //
 
-/// GMT +7 datetime
+/// default offset in seconds, start with GMT +7
+static DEFAULT_OFFSET: AtomicI32 = AtomicI32::new(7 * 3600);
+
+/// set default offset of `now()`, `js_now()` and `datetime_ts()`
+pub fn set_default_offset(offset: UtcOffset) {
+    DEFAULT_OFFSET.store(offset.whole_seconds(), Ordering::Relaxed);
+}
+
+/// default offset, GMT +7 if never set
+pub fn default_offset() -> UtcOffset {
+    UtcOffset::from_whole_seconds(DEFAULT_OFFSET.load(Ordering::Relaxed)).unwrap_or(offset!(+7))
+}
+
+/// default offset datetime
+#[cfg(feature = "native")]
 pub fn now() -> PrimitiveDateTime {
-    let local = OffsetDateTime::now_utc().to_offset(offset!(+7));
-    PrimitiveDateTime::new(local.date(), local.time())
+    now_at(default_offset())
 }
 
-/// GMT +7 datetime
+/// datetime at `offset` from `clock::SystemClock`
+#[cfg(feature = "native")]
+pub fn now_at(offset: UtcOffset) -> PrimitiveDateTime {
+    SystemClock.now_at(offset)
+}
+
+/// default offset datetime
+#[cfg(feature = "wasm")]
 pub fn js_now() -> PrimitiveDateTime {
-    let local = OffsetDateTime::from_unix_timestamp(get_now_wasm() as i64)
-        .unwrap()
-        .to_offset(offset!(+7));
-    PrimitiveDateTime::new(local.date(), local.time())
+    js_now_at(default_offset())
+}
+
+/// datetime at `offset` from `clock::JsClock`
+#[cfg(feature = "wasm")]
+pub fn js_now_at(offset: UtcOffset) -> PrimitiveDateTime {
+    JsClock.now_at(offset)
 }
 
 /// UTC timestamp in seconds
+#[cfg(feature = "wasm")]
 pub fn get_now_wasm() -> u64 {
     // UTC in milliseconds
     let now = js_sys::Date::now() as u64;
@@ -70,27 +203,80 @@ pub fn get_now_wasm() -> u64 {
 
 /// parse ISO-8601 string to time::PrimitiveDateTime, error will be `None`, discard offset
 pub fn datetime_8601(text: &str) -> Option<PrimitiveDateTime> {
-    let sanitize = text.replace('T', " ");
-    let dt = sanitize.split(' ').map(str::trim).collect::<Vec<&str>>();
+    try_datetime_8601(text).ok()
+}
+/// same as `datetime_8601()`, error will be `ParseError`
+pub fn try_datetime_8601(text: &str) -> Result<PrimitiveDateTime, ParseError> {
+    if text.trim().is_empty() {
+        return Err(ParseError::Empty);
+    }
+    let dt = split_pos(text, &['T', ' ']);
     if dt.len() != 2 {
-        return None;
+        return Err(ParseError::WrongTokenCount {
+            expected: 2,
+            found: dt.len(),
+        });
     }
-    let date = date_8601(dt[0]);
-    let time = time_8601(dt[1]);
+    let date = try_date_8601(dt[0].1).map_err(|e| part_error(e, dt[0].0))?;
+    let time = try_time_8601(dt[1].1).map_err(|e| part_error(e, dt[1].0))?;
 
-    if let (Some(d), Some(t)) = (date, time) {
-        Some(PrimitiveDateTime::new(d, t))
-    } else {
-        None
-    }
+    Ok(PrimitiveDateTime::new(date, time))
 }
 /// parse ISO-8601 string to time::Date, error will be `None`
 pub fn date_8601(text: &str) -> Option<Date> {
-    Date::parse(text, &Iso8601::DEFAULT).ok()
+    try_date_8601(text).ok()
+}
+/// same as `date_8601()`, error will be `ParseError`
+pub fn try_date_8601(text: &str) -> Result<Date, ParseError> {
+    if text.trim().is_empty() {
+        return Err(ParseError::Empty);
+    }
+    Date::parse(text, &Iso8601::DEFAULT).map_err(|_| {
+        // explain with `YYYY-MM-DD`, other ISO-8601 forms are valid or not at all
+        let ymd = split_pos(text, &['-']);
+        if ymd.len() != 3 {
+            return ParseError::WrongTokenCount {
+                expected: 3,
+                found: ymd.len(),
+            };
+        }
+        let explain = || {
+            let (_, y) = number_pos(ymd[0])?;
+            let (position, month) = number_pos(ymd[1])?;
+            if month > 12 {
+                // not swap to MM/DD
+                return Err(ParseError::MonthOutOfRange { position, month });
+            }
+            date_from_pat_inner(number_pos(ymd[2])?, (position, month), y as i32)
+        };
+        explain()
+            .err()
+            .unwrap_or(ParseError::InvalidNumber { position: 0 })
+    })
 }
 /// parse ISO-8601 string to time::Time, error will be `None`, discard offset
 pub fn time_8601(text: &str) -> Option<Time> {
-    Time::parse(text, &Iso8601::DEFAULT).ok()
+    try_time_8601(text).ok()
+}
+/// same as `time_8601()`, error will be `ParseError`
+pub fn try_time_8601(text: &str) -> Result<Time, ParseError> {
+    if text.trim().is_empty() {
+        return Err(ParseError::Empty);
+    }
+    Time::parse(text, &Iso8601::DEFAULT).map_err(|_| {
+        // explain with `HH:MM`, seconds and offset are not explained
+        let hm = split_pos(text, &[':']);
+        if hm.len() < 2 {
+            return ParseError::WrongTokenCount {
+                expected: 2,
+                found: hm.len(),
+            };
+        }
+        let explain = || time_from_pat_inner(number_pos(hm[0])?, number_pos(hm[1])?);
+        explain()
+            .err()
+            .unwrap_or(ParseError::InvalidNumber { position: 0 })
+    })
 }
 
 // ===== ===== ===== ===== ===== ===== //
@@ -104,23 +290,40 @@ pub fn datetime_th(date_time: &PrimitiveDateTime) -> String {
 
     [date_th(&date), time_hm(&time)].join(" ")
 }
+/// parse PrimitiveDateTime to localized string `24 ส.ค.2521 05:25 น.` or `24 Aug 1978 05:25`
+pub fn datetime_locale(date_time: &PrimitiveDateTime, locale: &dyn Locale) -> String {
+    let date = date_time.date();
+    let time = date_time.time();
+
+    [date_locale(&date, locale), time_hm_locale(&time, locale)].join(" ")
+}
+/// same as `datetime_locale()` with seconds or milliseconds by `precision`, `24 ส.ค.2521 05:25:30 น.`
+pub fn datetime_locale_with(
+    date_time: &PrimitiveDateTime,
+    locale: &dyn Locale,
+    precision: Precision,
+) -> String {
+    let date = date_time.date();
+    let time = date_time.time();
+
+    [
+        date_locale(&date, locale),
+        time_locale_with(&time, locale, precision),
+    ]
+    .join(" ")
+}
 /// parse PrimitiveDateTime to Thai string `24 ส.ค.2521 05:25 น.`
 pub fn datetime_th_opt(date_time_opt: &Option<PrimitiveDateTime>) -> String {
     date_time_opt.as_ref().map(datetime_th).unwrap_or_default()
 }
-/// parse PrimitiveDateTime to Thai string `24 ส.ค.2521 05:25 น.`
+/// parse PrimitiveDateTime to Thai string `วันนี้ 05:25 น.` or `24 ส.ค.2521 05:25 น.`<br>
+/// see `relative::datetime_th_relative_to()` for more phrases
 pub fn datetime_th_relative(date_time: &PrimitiveDateTime) -> String {
-    let date_now = js_now().date();
-    let date = date_time.date();
-    let time = date_time.time();
-    let date_show = if date == date_now {
-        String::from("วันนี้")
-    } else if Some(date) == date_now.previous_day() {
-        String::from("เมื่อวาน")
-    } else {
-        date_th(&date)
-    };
-    [date_show, time_hm(&time)].join(" ")
+    relative::datetime_th_relative_to(
+        date_time,
+        &default_clock().now(),
+        &relative::RelativeThresholds::default(),
+    )
 }
 /// parse PrimitiveDateTime to Thai string `24 ส.ค.2521 05:25 น.`
 pub fn datetime_th_opt_relative(date_time_opt: &Option<PrimitiveDateTime>) -> String {
@@ -131,22 +334,30 @@ pub fn datetime_th_opt_relative(date_time_opt: &Option<PrimitiveDateTime>) -> St
 }
 /// parse Date to Thai string `24 ส.ค.2521`
 pub fn date_th(date: &Date) -> String {
+    date_locale(date, &Thai)
+}
+/// parse Date to localized string `24 ส.ค.2521` or `24 Aug 1978`
+pub fn date_locale(date: &Date, locale: &dyn Locale) -> String {
     let day = date.day();
     let month = date.month();
     let year = date.year();
 
-    format!("{} {}{}", day, month_thai(&month), year + 543)
+    format!(
+        "{} {}{}{}",
+        day,
+        locale.month_short(&month),
+        locale.month_year_separator(),
+        year + locale.era_offset()
+    )
 }
-/// parse Date to Thai string `24 ส.ค.2521`
+/// parse Date to Thai string `เมื่อวาน` or `24 ส.ค.2521`<br>
+/// see `relative::date_th_relative_to()` for more phrases
 pub fn date_th_relative(date: &Date) -> String {
-    let date_now = js_now().date();
-    if *date == date_now {
-        String::from("วันนี้")
-    } else if Some(date) == date_now.previous_day().as_ref() {
-        String::from("เมื่อวาน")
-    } else {
-        date_th(date)
-    }
+    relative::date_th_relative_to(
+        date,
+        &default_clock().today(),
+        &relative::RelativeThresholds::default(),
+    )
 }
 /// parse Option<Date> to Thai string `24 ส.ค.2521`
 pub fn date_th_opt_relative(date_opt: &Option<Date>) -> String {
@@ -158,9 +369,17 @@ pub fn date_th_opt(date_opt: &Option<Date>) -> String {
 }
 /// parse Time to Thai string `05:25 น.`
 pub fn time_hm(time: &Time) -> String {
+    time_hm_locale(time, &Thai)
+}
+/// parse Time to localized string `05:25 น.` or `05:25`
+pub fn time_hm_locale(time: &Time, locale: &dyn Locale) -> String {
     let hour = time.hour();
     let minutes = time.minute();
-    format!("{:0>2}:{:0>2} น.", hour, minutes)
+    format!("{:0>2}:{:0>2}{}", hour, minutes, locale.time_suffix())
+}
+/// same as `time_hm_locale()` with seconds or milliseconds by `precision`, `05:25:30 น.`
+pub fn time_locale_with(time: &Time, locale: &dyn Locale, precision: Precision) -> String {
+    format!("{}{}", time.js_string_with(precision), locale.time_suffix())
 }
 /// parse Option<Time> to Thai string `05:25 น.`
 pub fn time_hm_opt(time_opt: &Option<Time>) -> String {
@@ -196,21 +415,25 @@ pub fn time_str_hm(text: &str) -> String {
 
 /// floor value to `xx ชั่วโมง yy นาที`
 pub fn duration_hm(duration: Duration) -> String {
+    duration_hm_locale(duration, &Thai)
+}
+/// floor value to localized `xx ชั่วโมง yy นาที` or `xx hours yy minutes`<br>
+/// negative duration has `-` prefix, see `duration::duration_th()` for days and seconds
+pub fn duration_hm_locale(duration: Duration, locale: &dyn Locale) -> String {
     let secs = duration.whole_seconds();
-    let hours = secs / 3600;
-    let minutes = (secs % 3600) / 60;
-    [
-        (hours != 0)
-            .then(|| [&hours.to_string(), " ชั่วโมง"].concat())
-            .unwrap_or_default(),
-        (hours != 0 && minutes != 0)
-            .then(|| String::from(" "))
-            .unwrap_or_default(),
-        (minutes != 0)
-            .then(|| [&minutes.to_string(), " นาที"].concat())
-            .unwrap_or_default(),
-    ]
-    .concat()
+    let hours = secs.abs() / 3600;
+    let minutes = (secs.abs() % 3600) / 60;
+    let text = match (hours != 0, minutes != 0) {
+        (true, true) => [locale.hours(hours), locale.minutes(minutes)].join(" "),
+        (true, false) => locale.hours(hours),
+        (false, true) => locale.minutes(minutes),
+        (false, false) => String::new(),
+    };
+    if secs < 0 && !text.is_empty() {
+        ["-", &text].concat()
+    } else {
+        text
+    }
 }
 
 // ===== ===== ===== ===== ===== //
@@ -227,179 +450,369 @@ pub fn date_pat(date: &Date) -> String {
 }
 /// parse Time to `HH:MM`
 pub fn time_pat(time: &Time) -> String {
-    let hour = time.hour();
-    let minutes = time.minute();
-    format!("{:0>2}:{:0>2}", hour, minutes)
+    time_pat_with(time, Precision::Minutes)
+}
+/// parse Time to `HH:MM`, `HH:MM:SS` or `HH:MM:SS.sss`
+pub fn time_pat_with(time: &Time, precision: Precision) -> String {
+    time.js_string_with(precision)
 }
 /// parse PrimitiveDateTime to Buddhism `DD/MM/YYYY HH:MM`
 pub fn datetime_pat(date_time: &PrimitiveDateTime) -> String {
+    datetime_pat_with(date_time, Precision::Minutes)
+}
+/// parse PrimitiveDateTime to Buddhism `DD/MM/YYYY HH:MM`, `DD/MM/YYYY HH:MM:SS` or `DD/MM/YYYY HH:MM:SS.sss`
+pub fn datetime_pat_with(date_time: &PrimitiveDateTime, precision: Precision) -> String {
     let date = date_time.date();
     let time = date_time.time();
 
-    [date_pat(&date), time_pat(&time)].join(" ")
+    [date_pat(&date), time_pat_with(&time, precision)].join(" ")
 }
 
 /// parse Buddhism `DDMMYYYY HHMM` or `DD/MM/YYYY HH:MM` string to time::PrimitiveDateTime, error will be `None`<br>
 /// any `NOT-NUMERIC` seperate supported
 pub fn datetime_from_pat(text: &str) -> Option<PrimitiveDateTime> {
-    let dt = text.split(' ').map(str::trim).collect::<Vec<&str>>();
+    try_datetime_from_pat(text).ok()
+}
+/// same as `datetime_from_pat()`, error will be `ParseError`
+pub fn try_datetime_from_pat(text: &str) -> Result<PrimitiveDateTime, ParseError> {
+    try_datetime_from_pat_with(text, Precision::Minutes)
+}
+/// same as `try_datetime_from_pat()`, keep seconds or milliseconds by `precision`
+pub fn try_datetime_from_pat_with(
+    text: &str,
+    precision: Precision,
+) -> Result<PrimitiveDateTime, ParseError> {
+    try_datetime_from_pat_clock(text, precision, default_clock())
+}
+/// same as `try_datetime_from_pat_with()`, 2 digits year is in the current Buddhist century of `clock`
+pub fn try_datetime_from_pat_clock(
+    text: &str,
+    precision: Precision,
+    clock: &dyn Clock,
+) -> Result<PrimitiveDateTime, ParseError> {
+    datetime_from_pat_expand(text, precision, &current_century(clock))
+}
+/// same as `try_datetime_from_pat_with()`, 2 digits year is expanded by `pivot` from `today`
+pub fn try_datetime_from_pat_pivot(
+    text: &str,
+    precision: Precision,
+    pivot: YearPivot,
+    today: &Date,
+) -> Result<PrimitiveDateTime, ParseError> {
+    datetime_from_pat_expand(text, precision, &|y| pivot.expand(y, today.year() + 543))
+}
+fn datetime_from_pat_expand(
+    text: &str,
+    precision: Precision,
+    expand: &dyn Fn(i32) -> i32,
+) -> Result<PrimitiveDateTime, ParseError> {
+    if text.trim().is_empty() {
+        return Err(ParseError::Empty);
+    }
+    let dt = split_pos(text, &[' ']);
     if dt.len() != 2 {
-        return None;
+        return Err(ParseError::WrongTokenCount {
+            expected: 2,
+            found: dt.len(),
+        });
     }
-    let date = date_from_pat(dt[0]);
-    let time = time_from_pat(dt[1]);
+    let date = date_from_pat_expand(dt[0].1, expand).map_err(|e| part_error(e, dt[0].0))?;
+    let time = try_time_from_pat_with(dt[1].1, precision).map_err(|e| part_error(e, dt[1].0))?;
 
-    if let (Some(d), Some(t)) = (date, time) {
-        Some(PrimitiveDateTime::new(d, t))
-    } else {
-        None
-    }
+    Ok(PrimitiveDateTime::new(date, time))
 }
 /// parse Buddhism `DDMMYYYY` or `DD/MM/YYYY` string to time::Date, error will be `None`<br>
 /// any `NOT-NUMERIC` seperate supported
 pub fn date_from_pat(text: &str) -> Option<Date> {
-    // try str.split() method fitst, not allocate, faster
-    // failover with str.chars() method, allocate, slower
+    try_date_from_pat(text).ok()
+}
+/// same as `date_from_pat()`, error will be `ParseError`
+pub fn try_date_from_pat(text: &str) -> Result<Date, ParseError> {
+    try_date_from_pat_clock(text, default_clock())
+}
+/// same as `try_date_from_pat()`, 2 digits year is in the current Buddhist century of `clock`
+pub fn try_date_from_pat_clock(text: &str, clock: &dyn Clock) -> Result<Date, ParseError> {
+    date_from_pat_expand(text, &current_century(clock))
+}
+/// same as `date_from_pat()`, 2 digits year is expanded by `pivot` from `today`, error will be `None`
+pub fn date_from_pat_pivot(text: &str, pivot: YearPivot, today: &Date) -> Option<Date> {
+    try_date_from_pat_pivot(text, pivot, today).ok()
+}
+/// same as `date_from_pat_pivot()`, error will be `ParseError`
+pub fn try_date_from_pat_pivot(
+    text: &str,
+    pivot: YearPivot,
+    today: &Date,
+) -> Result<Date, ParseError> {
+    date_from_pat_expand(text, &|y| pivot.expand(y, today.year() + 543))
+}
+/// `expand` 2 digits year to full Buddhist era year
+fn date_from_pat_expand(text: &str, expand: &dyn Fn(i32) -> i32) -> Result<Date, ParseError> {
+    let (d, m, y) = date_parts_from_pat(text, expand)?;
+    date_from_pat_inner(d, m, y)
+}
+/// day and month as typed `(position, value)` with Common era year, not validated
+type DateParts = ((usize, u32), (usize, u32), i32);
+fn date_parts_from_pat(text: &str, expand: &dyn Fn(i32) -> i32) -> Result<DateParts, ParseError> {
+    if text.trim().is_empty() {
+        return Err(ParseError::Empty);
+    }
+    // try split method fitst, not allocate, faster
+    // failover with chars method, allocate, slower
     if text.contains(['/', '-', '.', ':']) {
-        let dmy = text
-            .split(['/', '-', '.', ':'])
-            .map(str::trim)
-            .collect::<Vec<&str>>();
+        let dmy = split_pos(text, &['/', '-', '.', ':']);
         if dmy.len() > 2 {
-            if let (Some(d), Some(m), Some(y)) = (
-                dmy[0].parse::<u8>().ok(),
-                dmy[1].parse::<u8>().ok(),
-                dmy[2].parse::<i32>().ok(),
-            ) {
-                let decate = ((js_now().year() + 543) / 100) * 100;
-                date_from_pat_inner(d, m, if y > 543 { y } else { y + decate } - 543)
-            } else {
-                None
-            }
+            let d = number_pos(dmy[0])?;
+            let m = number_pos(dmy[1])?;
+            let y = year_pos(dmy[2], expand)?;
+            Ok((d, m, y))
         } else {
-            None
+            Err(ParseError::WrongTokenCount {
+                expected: 3,
+                found: dmy.len(),
+            })
         }
     } else {
-        let c = text
-            .chars()
-            .filter(|c| c.is_numeric())
-            .collect::<Vec<char>>();
+        let c = digits(text);
         match c.len() {
             6..8 => {
-                let dmy = c
-                    .chunks(2)
-                    .map(|tuple| String::from_iter(tuple))
-                    .collect::<Vec<String>>();
-                if let (Some(d), Some(m), Some(y)) = (
-                    dmy[0].parse::<u8>().ok(),
-                    dmy[1].parse::<u8>().ok(),
-                    dmy[2].parse::<i32>().ok(),
-                ) {
-                    let decate = ((js_now().year() + 543) / 100) * 100;
-                    date_from_pat_inner(d, m, y + decate - 543)
-                } else {
-                    None
-                }
+                let d = digits_pos(&c[0..2])?;
+                let m = digits_pos(&c[2..4])?;
+                let (_, y) = digits_pos(&c[4..6])?;
+                Ok((d, m, expand(y as i32) - 543))
             }
             8.. => {
-                if let (Some(d), Some(m), Some(y)) = (
-                    String::from_iter([c[0], c[1]]).parse::<u8>().ok(),
-                    String::from_iter([c[2], c[3]]).parse::<u8>().ok(),
-                    String::from_iter([c[4], c[5], c[6], c[7]])
-                        .parse::<i32>()
-                        .ok(),
-                ) {
-                    date_from_pat_inner(d, m, if y > 543 { y - 543 } else { y })
-                } else {
-                    None
-                }
+                let d = digits_pos(&c[0..2])?;
+                let m = digits_pos(&c[2..4])?;
+                let (_, y) = digits_pos(&c[4..8])?;
+                let y = y as i32;
+                Ok((d, m, if y > 543 { y - 543 } else { y }))
             }
-            _ => None,
+            found => Err(ParseError::MissingDigits { expected: 6, found }),
         }
     }
 }
-fn date_from_pat_inner(d: u8, m: u8, y: i32) -> Option<Date> {
+/// `d` and `m` are `(position, value)`, `y` is Common era
+fn date_from_pat_inner(d: (usize, u32), m: (usize, u32), y: i32) -> Result<Date, ParseError> {
     // convertable MMDDYYYY
-    if m > 12 && d < 13 {
-        if let Some(mm) = Month::try_from(d).ok() {
-            Date::from_calendar_date(y, mm, m).ok()
-        } else {
-            None
-        }
-    } else if let Some(mm) = Month::try_from(m).ok() {
-        Date::from_calendar_date(y, mm, d).ok()
-    } else {
-        None
-    }
+    let (d, m) = if m.1 > 12 && d.1 < 13 { (m, d) } else { (d, m) };
+    let month = u8::try_from(m.1)
+        .ok()
+        .and_then(|mm| Month::try_from(mm).ok())
+        .ok_or(ParseError::MonthOutOfRange {
+            position: m.0,
+            month: m.1,
+        })?;
+    u8::try_from(d.1)
+        .ok()
+        .and_then(|dd| Date::from_calendar_date(y, month, dd).ok())
+        .ok_or(ParseError::DayOutOfRange {
+            position: d.0,
+            day: d.1,
+            month,
+            year: y,
+        })
 }
 /// parse `HHMM` or `HH:MM` string to time::Time, error will be `None`<br>
 /// any `NOT-NUMERIC` seperate supported
 pub fn time_from_pat(text: &str) -> Option<Time> {
-    // try str.split() method fitst, not allocate, faster
-    // failover with str.chars() method, allocate, slower
+    try_time_from_pat(text).ok()
+}
+/// same as `time_from_pat()`, error will be `ParseError`
+pub fn try_time_from_pat(text: &str) -> Result<Time, ParseError> {
+    try_time_from_pat_with(text, Precision::Minutes)
+}
+/// same as `try_time_from_pat()`, keep seconds `HH:MM:SS`, `HHMMSS` or milliseconds `HH:MM:SS.sss` by `precision`
+pub fn try_time_from_pat_with(text: &str, precision: Precision) -> Result<Time, ParseError> {
+    if text.trim().is_empty() {
+        return Err(ParseError::Empty);
+    }
+    // try split method fitst, not allocate, faster
+    // failover with chars method, allocate, slower
     if text.contains(['/', '-', '.', ':']) {
-        let hm = text
-            .split(['/', '-', '.', ':'])
-            .map(str::trim)
-            .collect::<Vec<&str>>();
+        let hm = split_pos(text, &['/', '-', '.', ':']);
         if hm.len() > 1 {
-            time_from_pat_inner(hm[0].parse::<u8>().ok(), hm[1].parse::<u8>().ok())
+            let time = time_from_pat_inner(number_pos(hm[0])?, number_pos(hm[1])?)?;
+            let second = match hm.get(2) {
+                Some(part) if precision > Precision::Minutes => Some(number_pos(*part)?),
+                _ => None,
+            };
+            let milli = match hm.get(3) {
+                Some(part) if precision > Precision::Seconds => {
+                    number_pos(*part)?;
+                    Some(fraction_milli(part.1))
+                }
+                _ => None,
+            };
+            time_with_second(time, second, milli)
         } else {
-            None
+            Err(ParseError::WrongTokenCount {
+                expected: 2,
+                found: hm.len(),
+            })
         }
     } else {
-        let c = text
-            .chars()
-            .filter(|c| c.is_numeric())
-            .collect::<Vec<char>>();
+        let c = digits(text);
         match c.len() {
             4.. => {
-                let hm = c
-                    .chunks(2)
-                    .take(2)
-                    .map(|tuple| String::from_iter(tuple))
-                    .collect::<Vec<String>>();
-                time_from_pat_inner(hm[0].parse::<u8>().ok(), hm[1].parse::<u8>().ok())
+                let time = time_from_pat_inner(digits_pos(&c[0..2])?, digits_pos(&c[2..4])?)?;
+                let second = match c.get(4..6) {
+                    Some(cc) if precision > Precision::Minutes => Some(digits_pos(cc)?),
+                    _ => None,
+                };
+                let milli = match c.get(6..c.len().min(9)) {
+                    Some(cc) if !cc.is_empty() && precision > Precision::Seconds => {
+                        digits_pos(cc)?;
+                        Some(fraction_milli(&String::from_iter(
+                            cc.iter().map(|(_, c)| c),
+                        )))
+                    }
+                    _ => None,
+                };
+                time_with_second(time, second, milli)
             }
             3 => {
-                let end_two = String::from_iter([c[1], c[2]]).parse::<u8>().ok();
-                if end_two.map(|u| u > 60).unwrap_or_default() {
-                    let first_two = String::from_iter([c[0], c[1]]).parse::<u8>().ok();
-                    if first_two.map(|u| u < 24).unwrap_or_default() {
-                        time_from_pat_inner(
-                            String::from_iter([c[0], c[1]]).parse::<u8>().ok(),
-                            String::from_iter([c[2]]).parse::<u8>().ok(),
-                        )
+                let end_two = digits_pos(&c[1..3])?;
+                if end_two.1 > 60 {
+                    let first_two = digits_pos(&c[0..2])?;
+                    if first_two.1 < 24 {
+                        time_from_pat_inner(first_two, digits_pos(&c[2..3])?)
                     } else {
-                        None
+                        Err(ParseError::HourOutOfRange {
+                            position: first_two.0,
+                            hour: first_two.1,
+                        })
                     }
                 } else {
-                    time_from_pat_inner(
-                        String::from_iter([c[0]]).parse::<u8>().ok(),
-                        String::from_iter([c[1], c[2]]).parse::<u8>().ok(),
-                    )
+                    time_from_pat_inner(digits_pos(&c[0..1])?, end_two)
                 }
             }
             2 => {
-                if (c[0] == '1') || (c[0] == '2' && ['0', '1', '2', '3'].contains(&c[1])) {
-                    time_from_pat_inner(String::from_iter([c[0], c[1]]).parse::<u8>().ok(), Some(0))
+                if (c[0].1 == '1') || (c[0].1 == '2' && ['0', '1', '2', '3'].contains(&c[1].1)) {
+                    time_from_pat_inner(digits_pos(&c[0..2])?, (c[1].0, 0))
                 } else {
-                    time_from_pat_inner(
-                        String::from_iter([c[0]]).parse::<u8>().ok(),
-                        String::from_iter([c[1]]).parse::<u8>().ok(),
-                    )
+                    time_from_pat_inner(digits_pos(&c[0..1])?, digits_pos(&c[1..2])?)
                 }
             }
-            1 => time_from_pat_inner(String::from_iter([c[0]]).parse::<u8>().ok(), Some(0)),
-            0 => None,
+            1 => time_from_pat_inner(digits_pos(&c[0..1])?, (c[0].0, 0)),
+            found => Err(ParseError::MissingDigits { expected: 1, found }),
+        }
+    }
+}
+/// `h` and `m` are `(position, value)`
+fn time_from_pat_inner(h: (usize, u32), m: (usize, u32)) -> Result<Time, ParseError> {
+    let hour = u8::try_from(h.1)
+        .ok()
+        .filter(|hh| *hh < 24)
+        .ok_or(ParseError::HourOutOfRange {
+            position: h.0,
+            hour: h.1,
+        })?;
+    let minute = m.1;
+    u8::try_from(minute)
+        .ok()
+        .and_then(|mm| Time::from_hms(hour, mm, 0).ok())
+        .ok_or(ParseError::MinuteOutOfRange {
+            position: m.0,
+            minute,
+        })
+}
+/// `s` is `(position, value)`, `ms` is milliseconds
+fn time_with_second(
+    time: Time,
+    s: Option<(usize, u32)>,
+    ms: Option<u16>,
+) -> Result<Time, ParseError> {
+    let (position, second) = s.unwrap_or_default();
+    u8::try_from(second)
+        .ok()
+        .and_then(|ss| {
+            Time::from_hms_milli(time.hour(), time.minute(), ss, ms.unwrap_or_default()).ok()
+        })
+        .ok_or(ParseError::SecondOutOfRange { position, second })
+}
+/// fraction digits to milliseconds, `5` is 500, `1234` is 123
+fn fraction_milli(part: &str) -> u16 {
+    part.chars()
+        .chain(std::iter::repeat('0'))
+        .take(3)
+        .filter_map(|c| c.to_digit(10))
+        .fold(0, |ms, d| ms * 10 + d as u16)
+}
+
+/// split `text` by `separators` to trimmed parts with their byte positions
+fn split_pos<'a>(text: &'a str, separators: &[char]) -> Vec<(usize, &'a str)> {
+    let mut parts = Vec::new();
+    let mut start = 0;
+    for (i, c) in text.char_indices() {
+        if separators.contains(&c) {
+            parts.push(trim_pos(start, &text[start..i]));
+            start = i + c.len_utf8();
         }
     }
+    parts.push(trim_pos(start, &text[start..]));
+    parts
+}
+fn trim_pos(start: usize, part: &str) -> (usize, &str) {
+    let trimmed = part.trim_start();
+    (start + part.len() - trimmed.len(), trimmed.trim_end())
 }
-fn time_from_pat_inner(h_opt: Option<u8>, m_opt: Option<u8>) -> Option<Time> {
-    if let (Some(h), Some(m)) = (h_opt, m_opt) {
-        Time::from_hms(h, m, 0).ok()
+/// parse a part from `split_pos()` to `(position, value)`
+fn number_pos((position, part): (usize, &str)) -> Result<(usize, u32), ParseError> {
+    if let Some((i, c)) = part.char_indices().find(|(_, c)| !c.is_ascii_digit()) {
+        return Err(if c.is_numeric() {
+            ParseError::InvalidNumber {
+                position: position + i,
+            }
+        } else {
+            ParseError::BadSeparator {
+                position: position + i,
+                found: c,
+            }
+        });
+    }
+    part.parse::<u32>()
+        .map(|n| (position, n))
+        .map_err(|_| ParseError::InvalidNumber { position })
+}
+/// parse a year part from `split_pos()` to Common era, year up to 543 is a short year of `expand`
+fn year_pos(part: (usize, &str), expand: &dyn Fn(i32) -> i32) -> Result<i32, ParseError> {
+    let (_, y) = number_pos(part)?;
+    let y = y as i32;
+    if y > 543 {
+        Ok(y - 543)
     } else {
-        None
+        Ok(expand(y) - 543)
+    }
+}
+
+/// `YearPivot::CurrentCentury` from `clock`, only called for year up to 543
+fn current_century(clock: &dyn Clock) -> impl Fn(i32) -> i32 + '_ {
+    move |short| YearPivot::CurrentCentury.expand(short, clock.today().year() + 543)
+}
+/// numeric chars with their byte positions
+fn digits(text: &str) -> Vec<(usize, char)> {
+    text.char_indices()
+        .filter(|(_, c)| c.is_numeric())
+        .collect()
+}
+/// parse chars from `digits()` to `(position, value)`
+fn digits_pos(c: &[(usize, char)]) -> Result<(usize, u32), ParseError> {
+    let position = c[0].0;
+    String::from_iter(c.iter().map(|(_, c)| c))
+        .parse::<u32>()
+        .map(|n| (position, n))
+        .map_err(|_| ParseError::InvalidNumber { position })
+}
+/// shift error of a part, empty part is a missing part
+fn part_error(e: ParseError, position: usize) -> ParseError {
+    if e == ParseError::Empty {
+        ParseError::WrongTokenCount {
+            expected: 2,
+            found: 1,
+        }
+    } else {
+        e.offset(position)
     }
 }
 
@@ -407,9 +820,38 @@ fn time_from_pat_inner(h_opt: Option<u8>, m_opt: Option<u8>) -> Option<Time> {
 //  Utilities  //
 // ===== ===== //
 
-/// get UNIX timestamp milliseconds from GMT+7 datetime
+/// get UNIX timestamp milliseconds from default offset datetime
 pub fn datetime_ts(date_time: &PrimitiveDateTime) -> i64 {
-    (date_time.assume_offset(offset!(+7)).unix_timestamp_nanos() / 1_000_000) as i64
+    datetime_ts_at(date_time, default_offset())
+}
+
+/// get UNIX timestamp milliseconds from datetime at `offset`
+pub fn datetime_ts_at(date_time: &PrimitiveDateTime, offset: UtcOffset) -> i64 {
+    (date_time.assume_offset(offset).unix_timestamp_nanos() / 1_000_000) as i64
+}
+
+/// get default offset datetime from UNIX timestamp milliseconds
+pub fn datetime_from_ts(millis: i64) -> Option<PrimitiveDateTime> {
+    datetime_from_ts_at(millis, default_offset())
+}
+
+/// get datetime at `offset` from UNIX timestamp milliseconds
+pub fn datetime_from_ts_at(millis: i64, offset: UtcOffset) -> Option<PrimitiveDateTime> {
+    OffsetDateTime::from_unix_timestamp_nanos(millis as i128 * 1_000_000)
+        .ok()
+        .map(|utc| utc.to_offset(offset))
+        .map(|local| PrimitiveDateTime::new(local.date(), local.time()))
+}
+
+/// offset label `GMT+7`, `GMT+5:30` or `GMT-3:30`
+pub fn offset_label(offset: &UtcOffset) -> String {
+    let (hours, minutes, _) = offset.as_hms();
+    let sign = if offset.is_negative() { "-" } else { "+" };
+    if minutes == 0 {
+        format!("GMT{}{}", sign, hours.abs())
+    } else {
+        format!("GMT{}{}:{:02}", sign, hours.abs(), minutes.abs())
+    }
 }
 
 pub fn minutes_after_midnight(t: &Time) -> u16 {
@@ -418,13 +860,45 @@ pub fn minutes_after_midnight(t: &Time) -> u16 {
     h.saturating_mul(24).saturating_add(m)
 }
 
-// /// parse Ulid to Thai GMT+7 string `24 ส.ค.2521 05:25 น.`
-// pub fn datetime_th_from_ulid(uid: &Ulid) -> String {
-//     OffsetDateTime::from_unix_timestamp((uid.timestamp_ms() / 1000) as i64)
-//         .map(|utc| utc.to_offset(offset!(+7)))
-//         .map(|local| [date_th(&local.date()), time_hm(&local.time())].join(" "))
-//         .unwrap_or_default()
-// }
+/// add calendar months, day will be clamped to the end of month, `31 ม.ค.` + 1 = `28 ก.พ.`
+pub fn add_months(date: &Date, months: i64) -> Option<Date> {
+    let total = (date.year() as i64 * 12 + date.month() as i64 - 1).checked_add(months)?;
+    let year = i32::try_from(total.div_euclid(12)).ok()?;
+    let month = Month::try_from(total.rem_euclid(12) as u8 + 1).ok()?;
+    let day = date.day().min(month.length(year));
+    Date::from_calendar_date(year, month, day).ok()
+}
+
+/// get default offset datetime from Ulid timestamp, `None` if out of range
+#[cfg(feature = "ulid")]
+pub fn datetime_from_ulid(uid: &Ulid) -> Option<PrimitiveDateTime> {
+    i64::try_from(uid.timestamp_ms())
+        .ok()
+        .and_then(datetime_from_ts)
+}
+/// parse Ulid to Thai string at default offset `24 ส.ค.2521 05:25 น.`
+#[cfg(feature = "ulid")]
+pub fn datetime_th_from_ulid(uid: &Ulid) -> String {
+    datetime_from_ulid(uid)
+        .map(|dt| datetime_th(&dt))
+        .unwrap_or_default()
+}
+/// the lowest Ulid of default offset datetime, `uid >= ulid_lowest(start)` in range query<br>
+/// `None` before 1970
+#[cfg(feature = "ulid")]
+pub fn ulid_lowest(date_time: &PrimitiveDateTime) -> Option<Ulid> {
+    u64::try_from(datetime_ts(date_time))
+        .ok()
+        .map(|millis| Ulid::from_parts(millis, 0))
+}
+/// the highest Ulid of default offset datetime, `uid <= ulid_highest(end)` in range query<br>
+/// `None` before 1970
+#[cfg(feature = "ulid")]
+pub fn ulid_highest(date_time: &PrimitiveDateTime) -> Option<Ulid> {
+    u64::try_from(datetime_ts(date_time))
+        .ok()
+        .map(|millis| Ulid::from_parts(millis, u128::MAX))
+}
 
 pub fn month_thai(mm: &Month) -> &'static str {
     match mm {
@@ -472,12 +946,25 @@ pub fn weekday_thai(ww: &Weekday) -> &'static str {
     }
 }
 
+pub fn weekday_thai_full(ww: &Weekday) -> &'static str {
+    match ww {
+        Weekday::Sunday => "อาทิตย์",
+        Weekday::Monday => "จันทร์",
+        Weekday::Tuesday => "อังคาร",
+        Weekday::Wednesday => "พุธ",
+        Weekday::Thursday => "พฤหัสบดี",
+        Weekday::Friday => "ศุกร์",
+        Weekday::Saturday => "เสาร์",
+    }
+}
+
 #[cfg(test)]
 #[rustfmt::skip]
 pub mod tests {
 
     use super::*;
-    use time::macros::{date, datetime, time};
+    use crate::clock::FixedClock;
+    use time::macros::{date, datetime, offset, time};
 
     #[test]
     fn test_datetime_8601() {
@@ -529,6 +1016,12 @@ pub mod tests {
         assert_eq!(date_th(&date!(2024-01-30)), String::from("30 ม.ค.2567"));
     }
 
+    #[test]
+    fn test_datetime_locale() {
+        assert_eq!(datetime_locale(&datetime!(2024-01-30 01:23), &Thai), String::from("30 ม.ค.2567 01:23 น."));
+        assert_eq!(datetime_locale(&datetime!(2024-01-30 01:23), &locale::English), String::from("30 Jan 2024 01:23"));
+    }
+
     #[test]
     fn test_time_hm() {
         assert_eq!(time_hm(&time!(1:23)), String::from("01:23 น."));
@@ -580,59 +1073,169 @@ pub mod tests {
         assert_eq!(duration_hm(Duration::new(119, 0)), String::from("1 นาที"));
         assert_eq!(duration_hm(Duration::new(9 * 60 * 60, 0)), String::from("9 ชั่วโมง"));
         assert_eq!(duration_hm(Duration::new((9 * 60 * 60) + 60, 0)), String::from("9 ชั่วโมง 1 นาที"));
+        assert_eq!(duration_hm_locale(Duration::new((9 * 60 * 60) + 60, 0), &locale::English), String::from("9 hours 1 minute"));
+        assert_eq!(duration_hm(Duration::new(-90 * 60, 0)), String::from("-1 ชั่วโมง 30 นาที"));
     }
 
     #[test]
     fn test_datetime_ts() {
         assert_eq!(datetime_ts(&datetime!(2024-01-30 01:23:45)), 1706552625000);
+        assert_eq!(datetime_ts_at(&datetime!(2024-01-30 01:23:45), UtcOffset::UTC), 1706577825000);
+        assert_eq!(datetime_from_ts_at(1706577825000, UtcOffset::UTC), Some(datetime!(2024-01-30 01:23:45)));
+        assert_eq!(datetime_from_ts_at(1706552625123, offset!(+7)), Some(datetime!(2024-01-30 01:23:45.123)));
     }
 
-    // #[test]
-    // fn test_datetime_th_from_ulid() {
-    //     assert_eq!(datetime_th_from_ulid(&Ulid::from_parts(1706552625000, 1)), String::from("30 ม.ค.2567 01:23 น."));
-    // }
-}
+    #[test]
+    fn test_offset_label() {
+        assert_eq!(offset_label(&offset!(+7)), String::from("GMT+7"));
+        assert_eq!(offset_label(&offset!(+5:30)), String::from("GMT+5:30"));
+        assert_eq!(offset_label(&offset!(-3:30)), String::from("GMT-3:30"));
+        assert_eq!(offset_label(&UtcOffset::UTC), String::from("GMT+0"));
+    }
 
-#[cfg(test)]
-// #[allow(dead_code)]
-#[rustfmt::skip]
-pub mod wasm_tests {
-    // test with > wasm-pack test --chrome picker-util
-    use super::*;
-    use time::macros::{date, datetime, time};
-    use wasm_bindgen_test::*;
+    #[test]
+    fn test_try_date_from_pat() {
+        assert_eq!(try_date_from_pat("30/01/2565"), Ok(date!(2022-01-30)));
+        assert_eq!(try_date_from_pat(" "), Err(ParseError::Empty));
+        assert_eq!(try_date_from_pat("30/01"), Err(ParseError::WrongTokenCount { expected: 3, found: 2 }));
+        assert_eq!(try_date_from_pat("30/01x/2565"), Err(ParseError::BadSeparator { position: 5, found: 'x' }));
+        assert_eq!(try_date_from_pat("30//2565"), Err(ParseError::InvalidNumber { position: 3 }));
+        assert_eq!(try_date_from_pat("13/13/2565"), Err(ParseError::MonthOutOfRange { position: 3, month: 13 }));
+        assert_eq!(try_date_from_pat("30/0/2565"), Err(ParseError::MonthOutOfRange { position: 3, month: 0 }));
+        assert_eq!(try_date_from_pat("31/02/2568"), Err(ParseError::DayOutOfRange { position: 0, day: 31, month: Month::February, year: 2025 }));
+        // MM/DD swapped, day is the first part
+        assert_eq!(try_date_from_pat("2/31/2568"), Err(ParseError::DayOutOfRange { position: 2, day: 31, month: Month::February, year: 2025 }));
+        // 3 digits year above 543 is Buddhist era
+        assert_eq!(try_date_from_pat("30/01/600"), Ok(date!(0057-01-30)));
+        assert_eq!(try_date_from_pat("30012"), Err(ParseError::MissingDigits { expected: 6, found: 5 }));
+        assert_eq!(try_date_from_pat("30 02 2568"), Err(ParseError::DayOutOfRange { position: 0, day: 30, month: Month::February, year: 2025 }));
+    }
+
+    #[test]
+    fn test_year_pivot() {
+        let today = date!(2025-06-15);
+        assert_eq!(YearPivot::CurrentCentury.expand(99, 2568), 2599);
+        assert_eq!(YearPivot::Century(2400).expand(99, 2568), 2499);
+        assert_eq!(YearPivot::Past.expand(99, 2568), 2499);
+        assert_eq!(YearPivot::Past.expand(68, 2568), 2568);
+        assert_eq!(YearPivot::Window { future: 20 }.expand(88, 2568), 2588);
+        assert_eq!(YearPivot::Window { future: 20 }.expand(89, 2568), 2489);
+        assert_eq!(YearPivot::Window { future: 20 }.expand(10, 2598), 2610);
+        assert_eq!(date_from_pat_pivot("24/08/99", YearPivot::Past, &today), Some(date!(1956-08-24)));
+        assert_eq!(date_from_pat_pivot("240899", YearPivot::Past, &today), Some(date!(1956-08-24)));
+        assert_eq!(date_from_pat_pivot("24/08/99", YearPivot::CurrentCentury, &today), Some(date!(2056-08-24)));
+        assert_eq!(date_from_pat_pivot("24/08/2599", YearPivot::Past, &today), Some(date!(2056-08-24)));
+        assert_eq!(try_date_from_pat_pivot("24/08/999", YearPivot::Past, &today), Ok(date!(0456-08-24)));
+        assert_eq!(try_datetime_from_pat_pivot("24/08/99 13:00", Precision::Minutes, YearPivot::Window { future: 20 }, &today), Ok(datetime!(1956-08-24 13:00)));
+    }
 
-    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+    #[test]
+    fn test_try_time_from_pat() {
+        assert_eq!(try_time_from_pat("14:55"), Ok(time!(14:55)));
+        assert_eq!(try_time_from_pat(""), Err(ParseError::Empty));
+        assert_eq!(try_time_from_pat("25:55"), Err(ParseError::HourOutOfRange { position: 0, hour: 25 }));
+        assert_eq!(try_time_from_pat("14: 65"), Err(ParseError::MinuteOutOfRange { position: 4, minute: 65 }));
+        assert_eq!(try_time_from_pat("14:5x"), Err(ParseError::BadSeparator { position: 4, found: 'x' }));
+        assert_eq!(try_time_from_pat("9999"), Err(ParseError::HourOutOfRange { position: 0, hour: 99 }));
+        assert_eq!(try_time_from_pat("999"), Err(ParseError::HourOutOfRange { position: 0, hour: 99 }));
+        assert_eq!(try_time_from_pat("060"), Err(ParseError::MinuteOutOfRange { position: 1, minute: 60 }));
+        assert_eq!(try_time_from_pat("abc"), Err(ParseError::MissingDigits { expected: 1, found: 0 }));
+    }
+
+    #[test]
+    fn test_try_datetime_from_pat() {
+        assert_eq!(try_datetime_from_pat("30/01/2565 14:55"), Ok(datetime!(2022-01-30 14:55)));
+        assert_eq!(try_datetime_from_pat("30/01/2565T14:55"), Err(ParseError::WrongTokenCount { expected: 2, found: 1 }));
+        assert_eq!(try_datetime_from_pat("30/01/2565 "), Err(ParseError::WrongTokenCount { expected: 2, found: 1 }));
+        assert_eq!(try_datetime_from_pat("30/01/2565 14:65"), Err(ParseError::MinuteOutOfRange { position: 14, minute: 65 }));
+        assert_eq!(try_datetime_from_pat("31/02/2568 14:55").unwrap_err().position(), Some(0));
+    }
 
-    #[wasm_bindgen_test]
-    pub fn test_date_pat() {
+    #[test]
+    fn test_try_8601() {
+        assert_eq!(try_date_8601("2022-01-30"), Ok(date!(2022-01-30)));
+        assert_eq!(try_date_8601("2022-13-01"), Err(ParseError::MonthOutOfRange { position: 5, month: 13 }));
+        assert_eq!(try_date_8601("2025-02-29"), Err(ParseError::DayOutOfRange { position: 8, day: 29, month: Month::February, year: 2025 }));
+        assert_eq!(try_date_8601("2022/01/30"), Err(ParseError::WrongTokenCount { expected: 3, found: 1 }));
+        assert_eq!(try_time_8601("24:00"), Err(ParseError::HourOutOfRange { position: 0, hour: 24 }));
+        assert_eq!(try_datetime_8601("2022-01-30T14:60"), Err(ParseError::MinuteOutOfRange { position: 14, minute: 60 }));
+        assert_eq!(try_datetime_8601("2022-01-30T14:55:00"), Ok(datetime!(2022-01-30 14:55)));
+    }
+
+    #[test]
+    fn test_precision() {
+        let t = time!(14:55:30.123456);
+        assert_eq!(t.js_string(), String::from("14:55"));
+        assert_eq!(t.js_string_with(Precision::Seconds), String::from("14:55:30"));
+        assert_eq!(datetime!(2022-01-30 14:55:30.123456).js_string_with(Precision::Millis), String::from("2022-01-30T14:55:30.123"));
+        assert_eq!(datetime_pat_with(&datetime!(2022-01-30 14:55:30), Precision::Seconds), String::from("30/01/2565 14:55:30"));
+        assert_eq!(Precision::Millis.time_len(), time_pat_with(&t, Precision::Millis).len());
+    }
+
+    #[test]
+    fn test_try_time_from_pat_with() {
+        assert_eq!(try_time_from_pat_with("14:55:30", Precision::Minutes), Ok(time!(14:55)));
+        assert_eq!(try_time_from_pat_with("14:55:30", Precision::Seconds), Ok(time!(14:55:30)));
+        assert_eq!(try_time_from_pat_with("14:55", Precision::Seconds), Ok(time!(14:55)));
+        assert_eq!(try_time_from_pat_with("145530", Precision::Seconds), Ok(time!(14:55:30)));
+        assert_eq!(try_time_from_pat_with("14:55:30.5", Precision::Millis), Ok(time!(14:55:30.500)));
+        assert_eq!(try_time_from_pat_with("145530123", Precision::Millis), Ok(time!(14:55:30.123)));
+        assert_eq!(try_time_from_pat_with("14:55:60", Precision::Seconds), Err(ParseError::SecondOutOfRange { position: 6, second: 60 }));
+        assert_eq!(try_datetime_from_pat_with("30/01/2565 14:55:30", Precision::Seconds), Ok(datetime!(2022-01-30 14:55:30)));
+    }
+
+    #[test]
+    fn test_add_months() {
+        assert_eq!(add_months(&date!(2024-01-31), 1), Some(date!(2024-02-29)));
+        assert_eq!(add_months(&date!(2024-02-29), 12), Some(date!(2025-02-28)));
+        assert_eq!(add_months(&date!(2024-01-15), -13), Some(date!(2022-12-15)));
+    }
+
+    #[cfg(feature = "ulid")]
+    #[test]
+    fn test_datetime_th_from_ulid() {
+        let uid = Ulid::from_parts(1706552625000, 1);
+        assert_eq!(datetime_th_from_ulid(&uid), String::from("30 ม.ค.2567 01:23 น."));
+        assert_eq!(datetime_from_ulid(&uid), Some(datetime!(2024-01-30 01:23:45)));
+        let dt = datetime!(2024-01-30 01:23:45);
+        assert!(ulid_lowest(&dt).unwrap() <= uid);
+        assert!(ulid_highest(&dt).unwrap() >= uid);
+        assert!(ulid_highest(&datetime!(2024-01-30 01:23:44.999)).unwrap() < uid);
+        assert!(ulid_lowest(&datetime!(2024-01-30 01:23:45.001)).unwrap() > uid);
+        assert_eq!(datetime_from_ulid(&ulid_highest(&dt).unwrap()), Some(dt));
+        assert_eq!(ulid_lowest(&datetime!(1960-01-01 00:00)), None);
+    }
+
+    #[test]
+    fn test_date_pat() {
         assert_eq!(date_pat(&date!(2022-01-30)), String::from("30/01/2565"));
     }
 
-    #[wasm_bindgen_test]
-    pub fn test_time_pat() {
+    #[test]
+    fn test_time_pat() {
         assert_eq!(time_pat(&time!(14:55)), String::from("14:55"));
     }
 
-    #[wasm_bindgen_test]
-    pub fn test_datetime_pat() {
+    #[test]
+    fn test_datetime_pat() {
         assert_eq!(datetime_pat(&datetime!(2022-01-30 14:55)), String::from("30/01/2565 14:55"));
     }
 
-    #[wasm_bindgen_test]
-    pub fn test_date_from_pat() {
-        let decate = ((js_now().year() + 543) / 100) * 100;
+    #[test]
+    fn test_date_from_pat() {
+        // 2 digits year is in 25xx of 2025
+        let clock = FixedClock::at(datetime!(2025-08-06 10:00), offset!(+7));
         // DD/MM/YYYY'
         assert_eq!(date_from_pat("30/01/2565"), Some(date!(2022-01-30)));
-        assert_eq!(date_from_pat("30/01/65"), Date::from_calendar_date(decate + 65 - 543, Month::January, 30).ok());
-        assert_eq!(date_from_pat("2/1/65"), Date::from_calendar_date(decate + 65 - 543, Month::January, 2).ok());
+        assert_eq!(try_date_from_pat_clock("30/01/65", &clock), Ok(date!(2022-01-30)));
+        assert_eq!(try_date_from_pat_clock("2/1/65", &clock), Ok(date!(2022-01-02)));
         assert_eq!(date_from_pat("30012565"), Some(date!(2022-01-30)));
-        assert_eq!(date_from_pat("300165"), Date::from_calendar_date(decate + 65 - 543, Month::January, 30).ok());
+        assert_eq!(try_date_from_pat_clock("300165", &clock), Ok(date!(2022-01-30)));
         // MM/DD/YYYY
         assert_eq!(date_from_pat("11/13/2565"), Some(date!(2022-11-13)));
-        assert_eq!(date_from_pat("11/13/65"), Date::from_calendar_date(decate + 65 - 543, Month::November, 13).ok());
+        assert_eq!(try_date_from_pat_clock("11/13/65", &clock), Ok(date!(2022-11-13)));
         assert_eq!(date_from_pat("11132565"), Some(date!(2022-11-13)));
-        assert_eq!(date_from_pat("111365"), Date::from_calendar_date(decate + 65 - 543, Month::November, 13).ok());
+        assert_eq!(try_date_from_pat_clock("111365", &clock), Ok(date!(2022-11-13)));
         // seperators
         assert_eq!(date_from_pat("30-01-2565"), Some(date!(2022-01-30)));
         assert_eq!(date_from_pat("30.01.2565"), Some(date!(2022-01-30)));
@@ -641,7 +1244,7 @@ pub mod wasm_tests {
         assert_eq!(date_from_pat("30x01z2565"), Some(date!(2022-01-30)));
         // overflow
         assert_eq!(date_from_pat("300125659"), Some(date!(2022-01-30)));
-        assert_eq!(date_from_pat("3001659"), Date::from_calendar_date(decate + 65 - 543, Month::January, 30).ok());
+        assert_eq!(try_date_from_pat_clock("3001659", &clock), Ok(date!(2022-01-30)));
         // failed
         assert_eq!(date_from_pat("40/1/2565"), None);
         assert_eq!(date_from_pat("13/13/2565"), None);
@@ -649,8 +1252,8 @@ pub mod wasm_tests {
         assert_eq!(date_from_pat("12121"), None);
     }
 
-    #[wasm_bindgen_test]
-    pub fn test_time_from_pat() {
+    #[test]
+    fn test_time_from_pat() {
         assert_eq!(time_from_pat("14:55"), Some(time!(14:55)));
         assert_eq!(time_from_pat("2:55"), Some(time!(02:55)));
         assert_eq!(time_from_pat("2:5"), Some(time!(02:05)));
@@ -683,10 +1286,111 @@ pub mod wasm_tests {
         assert_eq!(time_from_pat(""), None);
     }
 
-    #[wasm_bindgen_test]
-    pub fn test_datetime_from_pat() {
+    #[test]
+    fn test_datetime_from_pat() {
         assert_eq!(datetime_from_pat("30/01/2565 14:55"), Some(datetime!(2022-01-30 14:55)));
+        let clock = FixedClock::at(datetime!(2125-08-06 10:00), offset!(+7));
+        assert_eq!(try_datetime_from_pat_clock("30/01/65 14:55", Precision::Minutes, &clock), Ok(datetime!(2122-01-30 14:55)));
+        assert_eq!(try_datetime_from_pat_clock("30/01/65 14:55:30", Precision::Seconds, &clock), Ok(datetime!(2122-01-30 14:55:30)));
         // failed
         assert_eq!(datetime_from_pat("30/01/2565T14:55"), None);
     }
+
+    // ===== round-trip properties ===== //
+
+    use proptest::prelude::*;
+
+    /// 1 CE to 9456 CE, Buddhist era `544` to `9999`, typed year up to 543 is a short year
+    fn pat_date() -> impl Strategy<Value = Date> {
+        (date!(0001-01-01).to_julian_day()..=date!(9456-12-31).to_julian_day()).prop_map(|day| Date::from_julian_day(day).unwrap())
+    }
+
+    /// 1 CE to 9999 CE, 4 digits year of ISO-8601
+    fn iso_date() -> impl Strategy<Value = Date> {
+        (date!(0001-01-01).to_julian_day()..=date!(9999-12-31).to_julian_day()).prop_map(|day| Date::from_julian_day(day).unwrap())
+    }
+
+    fn milli_time() -> impl Strategy<Value = Time> {
+        (0u8..24, 0u8..60, 0u8..60, 0u16..1000).prop_map(|(h, m, s, ms)| Time::from_hms_milli(h, m, s, ms).unwrap())
+    }
+
+    fn truncate(time: &Time, precision: Precision) -> Time {
+        match precision {
+            Precision::Minutes => Time::from_hms(time.hour(), time.minute(), 0).unwrap(),
+            Precision::Seconds => Time::from_hms(time.hour(), time.minute(), time.second()).unwrap(),
+            Precision::Millis => *time,
+        }
+    }
+
+    const PRECISIONS: [Precision; 3] = [Precision::Minutes, Precision::Seconds, Precision::Millis];
+
+    proptest! {
+        #[test]
+        fn prop_date_pat(date in pat_date()) {
+            let text = date_pat(&date);
+            prop_assert_eq!(date_from_pat(&text), Some(date));
+            // `DDMMYYYY` without separator, 3 digits year would be read as `DDMMYY`
+            if date.year() + 543 >= 1000 {
+                prop_assert_eq!(date_from_pat(&text.replace('/', "")), Some(date));
+            }
+        }
+
+        #[test]
+        fn prop_time_pat(time in milli_time()) {
+            let text = time_pat(&time);
+            prop_assert_eq!(time_from_pat(&text), Some(truncate(&time, Precision::Minutes)));
+            prop_assert_eq!(time_from_pat(&text.replace(':', "")), Some(truncate(&time, Precision::Minutes)));
+            for precision in PRECISIONS {
+                prop_assert_eq!(try_time_from_pat_with(&time_pat_with(&time, precision), precision), Ok(truncate(&time, precision)));
+            }
+        }
+
+        #[test]
+        fn prop_time_pat_3_digits(hour in 0u8..10, minute in 0u8..60) {
+            // `H:MM` when `MM` is a minute
+            prop_assert_eq!(time_from_pat(&format!("{hour}{minute:02}")), Time::from_hms(hour, minute, 0).ok());
+        }
+
+        #[test]
+        fn prop_time_pat_3_digits_hour(hour in prop::sample::select(vec![16u8, 17, 18, 19]), minute in 1u8..10) {
+            // otherwise `HH:M`, `161` is 16:01
+            prop_assert_eq!(time_from_pat(&format!("{hour}{minute}")), Time::from_hms(hour, minute, 0).ok());
+        }
+
+        #[test]
+        fn prop_datetime_pat(date in pat_date(), time in milli_time()) {
+            let date_time = PrimitiveDateTime::new(date, time);
+            prop_assert_eq!(datetime_from_pat(&datetime_pat(&date_time)), Some(PrimitiveDateTime::new(date, truncate(&time, Precision::Minutes))));
+            for precision in PRECISIONS {
+                let text = datetime_pat_with(&date_time, precision);
+                prop_assert_eq!(try_datetime_from_pat_with(&text, precision), Ok(PrimitiveDateTime::new(date, truncate(&time, precision))));
+            }
+        }
+
+        #[test]
+        fn prop_js_string(date in iso_date(), time in milli_time()) {
+            let date_time = PrimitiveDateTime::new(date, time);
+            prop_assert_eq!(date_8601(&date.to_string()), Some(date));
+            for precision in PRECISIONS {
+                prop_assert_eq!(time_8601(&time.js_string_with(precision)), Some(truncate(&time, precision)));
+                prop_assert_eq!(datetime_8601(&date_time.js_string_with(precision)), Some(PrimitiveDateTime::new(date, truncate(&time, precision))));
+            }
+        }
+
+        #[test]
+        fn prop_pat_free_form(text in "[0-9๐-๙/.:x -]{0,20}") {
+            // never panic, error is inside the text, parsed value is printed back to itself
+            for result in [try_date_from_pat(&text).map(|date| date.midnight()), try_time_from_pat(&text).map(|time| date!(2025-01-01).with_time(time)), try_datetime_from_pat(&text)] {
+                if let Err(e) = result {
+                    prop_assert!(e.position().is_none_or(|position| position <= text.len()), "{:?} in {:?}", e, text);
+                }
+            }
+            if let Some(date) = date_from_pat(&text).filter(|date| (1..=9456).contains(&date.year())) {
+                prop_assert_eq!(date_from_pat(&date_pat(&date)), Some(date));
+            }
+            if let Some(time) = time_from_pat(&text) {
+                prop_assert_eq!(time_from_pat(&time_pat(&time)), Some(time));
+            }
+        }
+    }
 }
@@ -0,0 +1,363 @@
+use time::{Month, Weekday};
+
+use crate::{ParseError, month_thai, month_thai_full, weekday_thai, weekday_thai_full};
+
+/// names, era and UI strings used by formatters and pickers
+pub trait Locale {
+    /// short month name, `ส.ค.` or `Aug`
+    fn month_short(&self, mm: &Month) -> &'static str;
+    /// full month name, `สิงหาคม` or `August`
+    fn month_full(&self, mm: &Month) -> &'static str;
+    /// short weekday name, `พ` or `We`
+    fn weekday_short(&self, ww: &Weekday) -> &'static str;
+    /// full weekday name, `พุธ` or `Wednesday`
+    fn weekday_full(&self, ww: &Weekday) -> &'static str;
+    /// added to CE year for display, `543` for Buddhist era
+    fn era_offset(&self) -> i32;
+    /// between short month name and year, Thai short names already end with `.`
+    fn month_year_separator(&self) -> &'static str;
+    /// appended to `HH:MM`, `" น."` for Thai
+    fn time_suffix(&self) -> &'static str;
+    /// `pattern::Pattern` of `date_locale()`, `D MMMBBBB` or `D MMM YYYY`
+    fn date_pattern(&self) -> &'static str;
+    /// `9 ชั่วโมง` or `9 hours`
+    fn hours(&self, hours: i64) -> String;
+    /// `1 นาที` or `1 minute`
+    fn minutes(&self, minutes: i64) -> String;
+    /// `45 ปี` or `45 years`
+    fn years(&self, years: i64) -> String;
+    /// `3 เดือน` or `3 months`
+    fn months(&self, months: i64) -> String;
+    /// `12 วัน` or `12 days`
+    fn days(&self, days: i64) -> String;
+
+    // ===== UI strings, English by default ===== //
+
+    /// clear button
+    fn clear(&self) -> &'static str {
+        "Clear"
+    }
+    /// select today button
+    fn today(&self) -> &'static str {
+        "Today"
+    }
+    /// select current time button
+    fn now(&self) -> &'static str {
+        "Now"
+    }
+    /// save and exit button
+    fn save(&self) -> &'static str {
+        "Save"
+    }
+    /// input placeholder prefix, `เช่น` or `e.g.`
+    fn example(&self) -> &'static str {
+        "e.g."
+    }
+    /// icon title of DateTime picker
+    fn show_datetime_picker(&self) -> &'static str {
+        "Show date and time picker"
+    }
+    /// icon title of Date picker
+    fn show_date_picker(&self) -> &'static str {
+        "Show date picker"
+    }
+    /// icon title of Time picker
+    fn show_time_picker(&self) -> &'static str {
+        "Show time picker"
+    }
+    /// chooser prefix of ambiguous input, `หมายถึง` or `Did you mean`
+    fn did_you_mean(&self) -> &'static str {
+        "Did you mean"
+    }
+    /// input feedback when every reading is forbidden or out of range
+    fn unavailable(&self) -> &'static str {
+        "Date not available"
+    }
+    /// input feedback of invalid text
+    fn parse_error(&self, error: &ParseError) -> String {
+        match error {
+            ParseError::Empty => String::from("Nothing to parse"),
+            ParseError::BadSeparator { found, .. } => format!("Unexpected separator '{found}'"),
+            ParseError::WrongTokenCount { expected, found } => {
+                format!("Expected {expected} parts, found {found}")
+            }
+            ParseError::MissingDigits { expected, found } => {
+                format!("Expected {expected} digits, found {found}")
+            }
+            ParseError::InvalidNumber { .. } => String::from("Invalid number"),
+            ParseError::MonthOutOfRange { month, .. } => {
+                format!("No month {month}, month must be 1-12")
+            }
+            ParseError::DayOutOfRange {
+                day, month, year, ..
+            } => format!("No day {day} in {} {year}", self.month_full(month)),
+            ParseError::HourOutOfRange { hour, .. } => {
+                format!("No hour {hour}, hour must be 0-23")
+            }
+            ParseError::MinuteOutOfRange { minute, .. } => {
+                format!("No minute {minute}, minute must be 0-59")
+            }
+            ParseError::SecondOutOfRange { second, .. } => {
+                format!("No second {second}, second must be 0-59")
+            }
+            ParseError::AmbiguousYear { year, .. } => {
+                format!("Year {year} is ambiguous, use 2 or 4 digits")
+            }
+            ParseError::UnknownMonth { .. } => String::from("Unknown month name"),
+            ParseError::UnknownWeekday { .. } => String::from("Unknown weekday name"),
+            ParseError::WeekdayMismatch {
+                found, expected, ..
+            } => format!(
+                "The date is {}, not {}",
+                self.weekday_full(expected),
+                self.weekday_full(found)
+            ),
+            ParseError::UnknownUnit { .. } => String::from("Unknown time unit"),
+            ParseError::TrailingCharacters { .. } => String::from("Unexpected text"),
+            ParseError::InvalidRule { .. } => String::from("Invalid recurrence rule"),
+            ParseError::InvalidCalendar { .. } => String::from("Invalid calendar data"),
+        }
+    }
+}
+
+/// Thai locale with Buddhist era
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Thai;
+
+impl Locale for Thai {
+    fn month_short(&self, mm: &Month) -> &'static str {
+        month_thai(mm)
+    }
+    fn month_full(&self, mm: &Month) -> &'static str {
+        month_thai_full(mm)
+    }
+    fn weekday_short(&self, ww: &Weekday) -> &'static str {
+        weekday_thai(ww)
+    }
+    fn weekday_full(&self, ww: &Weekday) -> &'static str {
+        weekday_thai_full(ww)
+    }
+    fn era_offset(&self) -> i32 {
+        543
+    }
+    fn month_year_separator(&self) -> &'static str {
+        ""
+    }
+    fn time_suffix(&self) -> &'static str {
+        " น."
+    }
+    fn date_pattern(&self) -> &'static str {
+        "D MMMBBBB"
+    }
+    fn hours(&self, hours: i64) -> String {
+        [&hours.to_string(), " ชั่วโมง"].concat()
+    }
+    fn minutes(&self, minutes: i64) -> String {
+        [&minutes.to_string(), " นาที"].concat()
+    }
+    fn years(&self, years: i64) -> String {
+        [&years.to_string(), " ปี"].concat()
+    }
+    fn months(&self, months: i64) -> String {
+        [&months.to_string(), " เดือน"].concat()
+    }
+    fn days(&self, days: i64) -> String {
+        [&days.to_string(), " วัน"].concat()
+    }
+    fn clear(&self) -> &'static str {
+        "ล้างข้อมูล"
+    }
+    fn today(&self) -> &'static str {
+        "วันนี้"
+    }
+    fn now(&self) -> &'static str {
+        "เวลานี้"
+    }
+    fn save(&self) -> &'static str {
+        "บันทึก"
+    }
+    fn example(&self) -> &'static str {
+        "เช่น"
+    }
+    fn show_datetime_picker(&self) -> &'static str {
+        "แสดงเครื่องมือเลือกวันที่และเวลา"
+    }
+    fn show_date_picker(&self) -> &'static str {
+        "แสดงเครื่องมือเลือกวันที่"
+    }
+    fn show_time_picker(&self) -> &'static str {
+        "แสดงเครื่องมือเลือกเวลา"
+    }
+    fn did_you_mean(&self) -> &'static str {
+        "หมายถึง"
+    }
+    fn unavailable(&self) -> &'static str {
+        "วันที่นี้เลือกไม่ได้"
+    }
+    fn parse_error(&self, error: &ParseError) -> String {
+        match error {
+            ParseError::Empty => String::from("ไม่มีข้อมูล"),
+            ParseError::BadSeparator { found, .. } => format!("ตัวคั่น '{found}' ไม่ถูกต้อง"),
+            ParseError::WrongTokenCount { expected, found } => {
+                format!("ต้องมี {expected} ส่วน แต่พบ {found} ส่วน")
+            }
+            ParseError::MissingDigits { expected, found } => {
+                format!("ต้องมีตัวเลข {expected} หลัก แต่พบ {found} หลัก")
+            }
+            ParseError::InvalidNumber { .. } => String::from("ตัวเลขไม่ถูกต้อง"),
+            ParseError::MonthOutOfRange { month, .. } => {
+                format!("ไม่มีเดือน {month} เดือนต้องเป็น 1-12")
+            }
+            ParseError::DayOutOfRange {
+                day, month, year, ..
+            } => format!(
+                "ไม่มีวันที่ {day} {} {}",
+                self.month_full(month),
+                year + self.era_offset()
+            ),
+            ParseError::HourOutOfRange { hour, .. } => {
+                format!("ไม่มี {hour} นาฬิกา ชั่วโมงต้องเป็น 0-23")
+            }
+            ParseError::MinuteOutOfRange { minute, .. } => {
+                format!("ไม่มี {minute} นาที นาทีต้องเป็น 0-59")
+            }
+            ParseError::SecondOutOfRange { second, .. } => {
+                format!("ไม่มี {second} วินาที วินาทีต้องเป็น 0-59")
+            }
+            ParseError::AmbiguousYear { year, .. } => {
+                format!("ปี {year} ไม่ชัดเจน ใช้ปี 2 หรือ 4 หลัก")
+            }
+            ParseError::UnknownMonth { .. } => String::from("ไม่รู้จักชื่อเดือน"),
+            ParseError::UnknownWeekday { .. } => String::from("ไม่รู้จักชื่อวัน"),
+            ParseError::WeekdayMismatch {
+                found, expected, ..
+            } => format!(
+                "วันที่นี้เป็นวัน{} ไม่ใช่วัน{}",
+                self.weekday_full(expected),
+                self.weekday_full(found)
+            ),
+            ParseError::UnknownUnit { .. } => String::from("ไม่รู้จักหน่วยเวลา"),
+            ParseError::TrailingCharacters { .. } => String::from("มีข้อความเกิน"),
+            ParseError::InvalidRule { .. } => String::from("รูปแบบการนัดซ้ำไม่ถูกต้อง"),
+            ParseError::InvalidCalendar { .. } => String::from("ข้อมูลปฏิทินไม่ถูกต้อง"),
+        }
+    }
+}
+
+/// English locale with Common era
+#[derive(Clone, Copy, Debug, Default)]
+pub struct English;
+
+impl Locale for English {
+    fn month_short(&self, mm: &Month) -> &'static str {
+        match mm {
+            Month::January => "Jan",
+            Month::February => "Feb",
+            Month::March => "Mar",
+            Month::April => "Apr",
+            Month::May => "May",
+            Month::June => "Jun",
+            Month::July => "Jul",
+            Month::August => "Aug",
+            Month::September => "Sep",
+            Month::October => "Oct",
+            Month::November => "Nov",
+            Month::December => "Dec",
+        }
+    }
+    fn month_full(&self, mm: &Month) -> &'static str {
+        match mm {
+            Month::January => "January",
+            Month::February => "February",
+            Month::March => "March",
+            Month::April => "April",
+            Month::May => "May",
+            Month::June => "June",
+            Month::July => "July",
+            Month::August => "August",
+            Month::September => "September",
+            Month::October => "October",
+            Month::November => "November",
+            Month::December => "December",
+        }
+    }
+    fn weekday_short(&self, ww: &Weekday) -> &'static str {
+        match ww {
+            Weekday::Sunday => "Su",
+            Weekday::Monday => "Mo",
+            Weekday::Tuesday => "Tu",
+            Weekday::Wednesday => "We",
+            Weekday::Thursday => "Th",
+            Weekday::Friday => "Fr",
+            Weekday::Saturday => "Sa",
+        }
+    }
+    fn weekday_full(&self, ww: &Weekday) -> &'static str {
+        match ww {
+            Weekday::Sunday => "Sunday",
+            Weekday::Monday => "Monday",
+            Weekday::Tuesday => "Tuesday",
+            Weekday::Wednesday => "Wednesday",
+            Weekday::Thursday => "Thursday",
+            Weekday::Friday => "Friday",
+            Weekday::Saturday => "Saturday",
+        }
+    }
+    fn era_offset(&self) -> i32 {
+        0
+    }
+    fn month_year_separator(&self) -> &'static str {
+        " "
+    }
+    fn time_suffix(&self) -> &'static str {
+        ""
+    }
+    fn date_pattern(&self) -> &'static str {
+        "D MMM YYYY"
+    }
+    fn hours(&self, hours: i64) -> String {
+        plural(hours, "hour")
+    }
+    fn minutes(&self, minutes: i64) -> String {
+        plural(minutes, "minute")
+    }
+    fn years(&self, years: i64) -> String {
+        plural(years, "year")
+    }
+    fn months(&self, months: i64) -> String {
+        plural(months, "month")
+    }
+    fn days(&self, days: i64) -> String {
+        plural(days, "day")
+    }
+}
+
+/// `1 hour`, `2 hours`
+fn plural(n: i64, unit: &str) -> String {
+    if n.abs() == 1 {
+        [&n.to_string(), " ", unit].concat()
+    } else {
+        [&n.to_string(), " ", unit, "s"].concat()
+    }
+}
+
+#[cfg(test)]
+#[rustfmt::skip]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_english_plural() {
+        assert_eq!(English.hours(1), String::from("1 hour"));
+        assert_eq!(English.hours(9), String::from("9 hours"));
+        assert_eq!(English.minutes(-1), String::from("-1 minute"));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = ParseError::DayOutOfRange { position: 0, day: 31, month: Month::February, year: 2025 };
+        assert_eq!(Thai.parse_error(&error), String::from("ไม่มีวันที่ 31 กุมภาพันธ์ 2568"));
+        assert_eq!(English.parse_error(&error), String::from("No day 31 in February 2025"));
+    }
+}
@@ -0,0 +1,270 @@
+use time::{Date, Duration, macros::date};
+
+/// first supported lunar year, starts at `EPOCH`
+const EPOCH_YEAR: i32 = 2400;
+const EPOCH: Date = date!(1856 - 11 - 28);
+/// last supported lunar year
+const LAST_YEAR: i32 = 2700;
+/// days between `EPOCH` and the mean new moon
+const EPOCH_DRIFT: f64 = -0.125_890_124;
+const SYNODIC_MONTH: f64 = 29.530_588_853;
+/// add a day to month 7 when the calendar is behind the mean moon more than this
+const ADHIKAVARA_LIMIT: f64 = -0.95;
+/// adhikamas years in 19 years cycle, counted from BE 2547
+const ADHIKAMAS_CYCLE: [i32; 7] = [0, 3, 6, 8, 11, 14, 17];
+
+/// type of Thai lunar year
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LunarYearKind {
+    /// ปกติมาส, 354 days
+    Normal,
+    /// อธิกวาร, 355 days, month 7 has 30 days
+    LeapDay,
+    /// อธิกมาส, 384 days, month 8 is doubled
+    LeapMonth,
+}
+
+impl LunarYearKind {
+    pub fn days(&self) -> i64 {
+        match self {
+            Self::Normal => 354,
+            Self::LeapDay => 355,
+            Self::LeapMonth => 384,
+        }
+    }
+
+    /// `ปกติมาส`, `อธิกวาร` or `อธิกมาส`
+    pub fn name_th(&self) -> &'static str {
+        match self {
+            Self::Normal => "ปกติมาส",
+            Self::LeapDay => "อธิกวาร",
+            Self::LeapMonth => "อธิกมาส",
+        }
+    }
+
+    fn months(&self) -> i64 {
+        match self {
+            Self::LeapMonth => 13,
+            _ => 12,
+        }
+    }
+
+    /// `(month, second_eighth, days)` from month 1 (เดือนอ้าย)
+    fn month_list(&self) -> Vec<(u8, bool, i64)> {
+        let mut list = Vec::with_capacity(13);
+        for month in 1..=12u8 {
+            let days = if month % 2 == 0 || (month == 7 && *self == Self::LeapDay) {
+                30
+            } else {
+                29
+            };
+            list.push((month, false, days));
+            if month == 8 && *self == Self::LeapMonth {
+                list.push((8, true, 30));
+            }
+        }
+        list
+    }
+}
+
+/// Thai lunar date (จันทรคติ), `แรม 8 ค่ำ เดือน 9`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LunarDate {
+    /// Buddhist era of the lunar year which starts at month 1 around December,
+    /// ex. มาฆบูชา of `2568` is `12 ก.พ. 2568`
+    pub year: i32,
+    /// 1-12, 1 is `อ้าย` and 2 is `ยี่`
+    pub month: u8,
+    /// second month 8 (`8-8`) of อธิกมาส year
+    pub second_eighth: bool,
+    /// `ขึ้น` or `แรม`
+    pub waxing: bool,
+    /// 1-15 ค่ำ
+    pub day: u8,
+}
+
+impl LunarDate {
+    /// `ขึ้น 8 ค่ำ`, `ขึ้น 15 ค่ำ`, `แรม 8 ค่ำ` and the last day of month (วันพระ)
+    pub fn is_wan_phra(&self) -> bool {
+        if self.day == 8 || (self.waxing && self.day == 15) {
+            return true;
+        }
+        !self.waxing
+            && lunar_year_kind(self.year)
+                .and_then(|kind| {
+                    kind.month_list()
+                        .into_iter()
+                        .find(|(mm, second, _)| *mm == self.month && *second == self.second_eighth)
+                })
+                .is_some_and(|(_, _, days)| self.day as i64 == days - 15)
+    }
+}
+
+/// year type of Buddhist era lunar year, `None` if not in BE 2400-2700
+pub fn lunar_year_kind(year: i32) -> Option<LunarYearKind> {
+    lunar_years()
+        .find(|(yy, _, _)| *yy == year)
+        .map(|(_, _, kind)| kind)
+}
+
+/// convert solar Date to Thai lunar date, `None` if not in BE 2400-2700
+pub fn lunar_from_date(date: &Date) -> Option<LunarDate> {
+    let (year, start, kind) = lunar_years()
+        .take_while(|(_, start, _)| start <= date)
+        .last()?;
+    let mut offset = (*date - start).whole_days();
+    for (month, second_eighth, days) in kind.month_list() {
+        if offset < days {
+            let day = offset as u8 + 1;
+            let waxing = day <= 15;
+            return Some(LunarDate {
+                year,
+                month,
+                second_eighth,
+                waxing,
+                day: if waxing { day } else { day - 15 },
+            });
+        }
+        offset -= days;
+    }
+    None
+}
+
+/// convert Thai lunar date to solar Date, `None` if the lunar date does not exist
+pub fn date_from_lunar(lunar: &LunarDate) -> Option<Date> {
+    let (_, start, kind) = lunar_years().find(|(yy, _, _)| *yy == lunar.year)?;
+    let mut offset = 0;
+    for (month, second_eighth, days) in kind.month_list() {
+        if month == lunar.month && second_eighth == lunar.second_eighth {
+            let max = if lunar.waxing { 15 } else { days - 15 };
+            if lunar.day == 0 || lunar.day as i64 > max {
+                return None;
+            }
+            let day = lunar.day as i64 + if lunar.waxing { 0 } else { 15 };
+            return start.checked_add(Duration::days(offset + day - 1));
+        }
+        offset += days;
+    }
+    None
+}
+
+/// `ขึ้น 8 ค่ำ`, `ขึ้น 15 ค่ำ`, `แรม 8 ค่ำ` and the last day of lunar month
+pub fn is_wan_phra(date: &Date) -> bool {
+    lunar_from_date(date).is_some_and(|lunar| lunar.is_wan_phra())
+}
+
+/// format LunarDate to `แรม 8 ค่ำ เดือน 9`, `ขึ้น 1 ค่ำ เดือนอ้าย` or `ขึ้น 15 ค่ำ เดือน 8-8`
+pub fn lunar_th(lunar: &LunarDate) -> String {
+    let month = match (lunar.month, lunar.second_eighth) {
+        (1, _) => String::from("อ้าย"),
+        (2, _) => String::from("ยี่"),
+        (8, true) => String::from(" 8-8"),
+        (mm, _) => format!(" {mm}"),
+    };
+    format!(
+        "{} {} ค่ำ เดือน{}",
+        if lunar.waxing {
+            "ขึ้น"
+        } else {
+            "แรม"
+        },
+        lunar.day,
+        month
+    )
+}
+
+/// `(year, start, kind)` from `EPOCH_YEAR` to `LAST_YEAR`<br>
+/// อธิกมาส follows 19 years cycle, อธิกวาร keeps month starts close to the mean new moon
+fn lunar_years() -> impl Iterator<Item = (i32, Date, LunarYearKind)> {
+    let mut days = 0;
+    let mut months = 0;
+    (EPOCH_YEAR..=LAST_YEAR).map(move |year| {
+        let kind = if ADHIKAMAS_CYCLE.contains(&(year - 2547).rem_euclid(19)) {
+            LunarYearKind::LeapMonth
+        } else {
+            let drift = (days + 354) as f64 - (months + 12) as f64 * SYNODIC_MONTH + EPOCH_DRIFT;
+            if drift < ADHIKAVARA_LIMIT {
+                LunarYearKind::LeapDay
+            } else {
+                LunarYearKind::Normal
+            }
+        };
+        let start = EPOCH + Duration::days(days);
+        days += kind.days();
+        months += kind.months();
+        (year, start, kind)
+    })
+}
+
+#[cfg(test)]
+#[rustfmt::skip]
+pub mod tests {
+
+    use super::*;
+
+    fn lunar(year: i32, month: u8, waxing: bool, day: u8) -> LunarDate {
+        LunarDate { year, month, second_eighth: false, waxing, day }
+    }
+
+    #[test]
+    fn test_lunar_year_kind() {
+        use LunarYearKind::*;
+        let kinds = (2558..=2569).map(|y| lunar_year_kind(y).unwrap()).collect::<Vec<_>>();
+        assert_eq!(kinds, vec![LeapMonth, LeapDay, Normal, LeapMonth, Normal, LeapDay, LeapMonth, Normal, LeapMonth, Normal, LeapDay, LeapMonth]);
+        assert_eq!(lunar_year_kind(2399), None);
+        assert_eq!(LeapDay.name_th(), "อธิกวาร");
+    }
+
+    #[test]
+    fn test_lunar_from_date() {
+        // มาฆบูชา
+        assert_eq!(lunar_from_date(&date!(2025-02-12)), Some(lunar(2568, 3, true, 15)));
+        assert_eq!(lunar_from_date(&date!(2026-03-03)), Some(lunar(2569, 4, true, 15)));
+        assert_eq!(lunar_from_date(&date!(2009-02-09)), Some(lunar(2552, 3, true, 15)));
+        // วิสาขบูชา
+        assert_eq!(lunar_from_date(&date!(2024-05-22)), Some(lunar(2567, 6, true, 15)));
+        assert_eq!(lunar_from_date(&date!(2023-06-03)), Some(lunar(2566, 7, true, 15)));
+        // อาสาฬหบูชา
+        assert_eq!(lunar_from_date(&date!(2025-07-10)), Some(lunar(2568, 8, true, 15)));
+        assert_eq!(lunar_from_date(&date!(2023-08-01)), Some(LunarDate { year: 2566, month: 8, second_eighth: true, waxing: true, day: 15 }));
+        // ออกพรรษา
+        assert_eq!(lunar_from_date(&date!(2025-10-07)), Some(lunar(2568, 11, true, 15)));
+        // เดือนอ้าย
+        assert_eq!(lunar_from_date(&date!(2024-12-01)), Some(lunar(2568, 1, true, 1)));
+        assert_eq!(lunar_from_date(&date!(2024-11-30)), Some(lunar(2567, 12, false, 15)));
+        assert_eq!(lunar_from_date(&date!(1800-01-01)), None);
+    }
+
+    #[test]
+    fn test_date_from_lunar() {
+        assert_eq!(date_from_lunar(&lunar(2568, 3, true, 15)), Some(date!(2025-02-12)));
+        assert_eq!(date_from_lunar(&lunar(2568, 3, false, 14)), Some(date!(2025-02-26)));
+        assert_eq!(date_from_lunar(&lunar(2568, 3, false, 15)), None);
+        assert_eq!(date_from_lunar(&lunar(2568, 3, true, 0)), None);
+        assert_eq!(date_from_lunar(&LunarDate { year: 2568, month: 8, second_eighth: true, waxing: true, day: 1 }), None);
+        let mut date = date!(2020-01-01);
+        while date < date!(2027-01-01) {
+            assert_eq!(lunar_from_date(&date).and_then(|l| date_from_lunar(&l)), Some(date));
+            date = date.next_day().unwrap();
+        }
+    }
+
+    #[test]
+    fn test_wan_phra() {
+        assert!(is_wan_phra(&date!(2025-02-12)));
+        assert!(is_wan_phra(&date!(2025-02-20)));
+        assert!(is_wan_phra(&date!(2025-02-26)));
+        assert!(!is_wan_phra(&date!(2025-02-27)));
+        // 30 days month
+        assert!(!is_wan_phra(&date!(2025-03-27)));
+        assert!(is_wan_phra(&date!(2025-03-28)));
+    }
+
+    #[test]
+    fn test_lunar_th() {
+        assert_eq!(lunar_th(&lunar(2568, 9, false, 8)), String::from("แรม 8 ค่ำ เดือน 9"));
+        assert_eq!(lunar_th(&lunar(2568, 1, true, 1)), String::from("ขึ้น 1 ค่ำ เดือนอ้าย"));
+        assert_eq!(lunar_th(&lunar(2568, 2, true, 1)), String::from("ขึ้น 1 ค่ำ เดือนยี่"));
+        assert_eq!(lunar_th(&LunarDate { year: 2566, month: 8, second_eighth: true, waxing: true, day: 15 }), String::from("ขึ้น 15 ค่ำ เดือน 8-8"));
+    }
+}
@@ -0,0 +1,363 @@
+use time::{Date, Month, PrimitiveDateTime, Time, Weekday};
+
+use crate::{
+    ParseError, YearPivot,
+    clock::{Clock, default_clock},
+    month_thai, month_thai_full, time_from_pat_inner, weekday_thai, weekday_thai_full,
+};
+
+pub(crate) const MONTHS: [Month; 12] = [
+    Month::January,
+    Month::February,
+    Month::March,
+    Month::April,
+    Month::May,
+    Month::June,
+    Month::July,
+    Month::August,
+    Month::September,
+    Month::October,
+    Month::November,
+    Month::December,
+];
+
+// `พฤ` before `พ` and `อา` before `อ`
+pub(crate) const WEEKDAYS: [Weekday; 7] = [
+    Weekday::Thursday,
+    Weekday::Sunday,
+    Weekday::Monday,
+    Weekday::Tuesday,
+    Weekday::Wednesday,
+    Weekday::Friday,
+    Weekday::Saturday,
+];
+
+/// parse Thai string `24 ส.ค.2521 05:25 น.` or `วันพฤหัสบดีที่ 24 สิงหาคม พ.ศ. 2521 เวลา 05.25 น.`
+/// to time::PrimitiveDateTime, error will be `None`
+pub fn datetime_from_th(text: &str) -> Option<PrimitiveDateTime> {
+    try_datetime_from_th(text).ok()
+}
+/// same as `datetime_from_th()`, error will be `ParseError`
+pub fn try_datetime_from_th(text: &str) -> Result<PrimitiveDateTime, ParseError> {
+    try_datetime_from_th_clock(text, default_clock())
+}
+/// same as `try_datetime_from_th()`, 2 digits year is in the century of `clock`
+pub fn try_datetime_from_th_clock(
+    text: &str,
+    clock: &dyn Clock,
+) -> Result<PrimitiveDateTime, ParseError> {
+    datetime_from_th_expand(text, YearPivot::CurrentCentury, &|| clock.today().year())
+}
+/// same as `try_datetime_from_th()`, 2 digits year is expanded by `pivot` from `today`<br>
+/// `ค.ศ.` year is expanded in Common era, `Century(2000)` makes `99` 2099
+pub fn try_datetime_from_th_pivot(
+    text: &str,
+    pivot: YearPivot,
+    today: &Date,
+) -> Result<PrimitiveDateTime, ParseError> {
+    datetime_from_th_expand(text, pivot, &|| today.year())
+}
+/// `today_year` is Common era, only called for 2 digits year
+fn datetime_from_th_expand(
+    text: &str,
+    pivot: YearPivot,
+    today_year: &dyn Fn() -> i32,
+) -> Result<PrimitiveDateTime, ParseError> {
+    let mut cursor = Cursor::new(text)?;
+    let date = cursor.date(pivot, today_year)?;
+    cursor.eat("เวลา");
+    let time = cursor.time()?;
+    cursor.end()?;
+
+    Ok(PrimitiveDateTime::new(date, time))
+}
+/// parse Thai string `24 ส.ค.2521` or `วันพฤหัสบดีที่ 24 สิงหาคม พ.ศ. 2521` to time::Date, error will be `None`<br>
+/// - month can be short or full name
+/// - year is Buddhist era unless `ค.ศ.`, 2 digits year is in current century
+/// - weekday prefix will be checked with the date
+pub fn date_from_th(text: &str) -> Option<Date> {
+    try_date_from_th(text).ok()
+}
+/// same as `date_from_th()`, error will be `ParseError`
+pub fn try_date_from_th(text: &str) -> Result<Date, ParseError> {
+    try_date_from_th_clock(text, default_clock())
+}
+/// same as `try_date_from_th()`, 2 digits year is in the century of `clock`
+pub fn try_date_from_th_clock(text: &str, clock: &dyn Clock) -> Result<Date, ParseError> {
+    date_from_th_expand(text, YearPivot::CurrentCentury, &|| clock.today().year())
+}
+/// same as `try_date_from_th()`, 2 digits year is expanded by `pivot` from `today`<br>
+/// `ค.ศ.` year is expanded in Common era, `Century(2000)` makes `99` 2099
+pub fn try_date_from_th_pivot(
+    text: &str,
+    pivot: YearPivot,
+    today: &Date,
+) -> Result<Date, ParseError> {
+    date_from_th_expand(text, pivot, &|| today.year())
+}
+/// `today_year` is Common era, only called for 2 digits year
+fn date_from_th_expand(
+    text: &str,
+    pivot: YearPivot,
+    today_year: &dyn Fn() -> i32,
+) -> Result<Date, ParseError> {
+    let mut cursor = Cursor::new(text)?;
+    let date = cursor.date(pivot, today_year)?;
+    cursor.end()?;
+
+    Ok(date)
+}
+/// parse Thai string `05:25 น.` or `05.25` to time::Time, error will be `None`
+pub fn time_from_th(text: &str) -> Option<Time> {
+    try_time_from_th(text).ok()
+}
+/// same as `time_from_th()`, error will be `ParseError`
+pub fn try_time_from_th(text: &str) -> Result<Time, ParseError> {
+    let mut cursor = Cursor::new(text)?;
+    cursor.eat("เวลา");
+    let time = cursor.time()?;
+    cursor.end()?;
+
+    Ok(time)
+}
+
+/// position in parsed text, all methods skip leading spaces
+pub(crate) struct Cursor<'a> {
+    text: &'a str,
+    pub(crate) position: usize,
+}
+
+impl<'a> Cursor<'a> {
+    pub(crate) fn new(text: &'a str) -> Result<Self, ParseError> {
+        if text.trim().is_empty() {
+            Err(ParseError::Empty)
+        } else {
+            Ok(Self { text, position: 0 })
+        }
+    }
+
+    pub(crate) fn rest(&mut self) -> &'a str {
+        let rest = &self.text[self.position..];
+        let trimmed = rest.trim_start();
+        self.position += rest.len() - trimmed.len();
+        trimmed
+    }
+
+    /// skip `word` if exists
+    pub(crate) fn eat(&mut self, word: &str) -> bool {
+        if self.rest().starts_with(word) {
+            self.position += word.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    pub(crate) fn end(&mut self) -> Result<(), ParseError> {
+        if self.rest().is_empty() {
+            Ok(())
+        } else {
+            Err(ParseError::TrailingCharacters {
+                position: self.position,
+            })
+        }
+    }
+
+    /// `(position, value, digits)`, Thai digits supported
+    pub(crate) fn number(&mut self) -> Result<(usize, u32, usize), ParseError> {
+        self.number_max(usize::MAX)
+    }
+
+    /// same as `number()`, stop after `max` digits
+    pub(crate) fn number_max(&mut self, max: usize) -> Result<(usize, u32, usize), ParseError> {
+        let rest = self.rest();
+        let position = self.position;
+        let mut value: u32 = 0;
+        let mut digits = 0;
+        for c in rest.chars().take(max) {
+            let digit = match c {
+                '0'..='9' => c as u32 - '0' as u32,
+                '๐'..='๙' => c as u32 - '๐' as u32,
+                _ => break,
+            };
+            value = value
+                .checked_mul(10)
+                .and_then(|v| v.checked_add(digit))
+                .ok_or(ParseError::InvalidNumber { position })?;
+            digits += 1;
+            self.position += c.len_utf8();
+        }
+        if digits == 0 {
+            Err(ParseError::InvalidNumber { position })
+        } else {
+            Ok((position, value, digits))
+        }
+    }
+
+    /// `วันพฤหัสบดีที่`, `พฤหัสบดี`, `พฤ.` or `วันที่`
+    fn weekday(&mut self) -> Option<(usize, Weekday)> {
+        self.eat("วัน");
+        let position = self.position;
+        let rest = self.rest();
+        let found = WEEKDAYS
+            .iter()
+            .find(|ww| rest.starts_with(weekday_thai_full(ww)))
+            .map(|ww| (*ww, weekday_thai_full(ww).len()))
+            .or_else(|| {
+                // short name must be followed by `.` or space, not to eat a month name
+                WEEKDAYS
+                    .iter()
+                    .find(|ww| {
+                        rest.strip_prefix(weekday_thai(ww))
+                            .and_then(|r| r.chars().next())
+                            .is_some_and(|c| c == '.' || c.is_whitespace())
+                    })
+                    .map(|ww| (*ww, weekday_thai(ww).len()))
+            });
+        if let Some((_, len)) = found {
+            self.position += len;
+            self.eat(".");
+        }
+        self.eat("ที่");
+        self.eat(",");
+        found.map(|(ww, _)| (position, ww))
+    }
+
+    fn month(&mut self) -> Result<Month, ParseError> {
+        let rest = self.rest();
+        let found = MONTHS
+            .iter()
+            .map(|mm| (*mm, month_thai_full(mm)))
+            .chain(MONTHS.iter().map(|mm| (*mm, month_thai(mm))))
+            .find(|(_, name)| rest.starts_with(name));
+        if let Some((mm, name)) = found {
+            self.position += name.len();
+            Ok(mm)
+        } else {
+            Err(ParseError::UnknownMonth {
+                position: self.position,
+            })
+        }
+    }
+
+    /// Common era year, `พ.ศ.` or `ค.ศ.` prefix, 2 digits year is expanded by `pivot`
+    fn year(&mut self, pivot: YearPivot, today_year: &dyn Fn() -> i32) -> Result<i32, ParseError> {
+        let era = if self.eat("ค.ศ") {
+            0
+        } else {
+            self.eat("พ.ศ");
+            543
+        };
+        self.eat(".");
+        let (position, y, digits) = self.number()?;
+        let y = y as i32;
+        match digits {
+            3 => Err(ParseError::AmbiguousYear { position, year: y }),
+            4.. => Ok(y - era),
+            _ => Ok(pivot.expand(y, today_year() + era) - era),
+        }
+    }
+
+    fn date(&mut self, pivot: YearPivot, today_year: &dyn Fn() -> i32) -> Result<Date, ParseError> {
+        let weekday = self.weekday();
+        let (position, day, _) = self.number()?;
+        let month = self.month()?;
+        let year = self.year(pivot, today_year)?;
+        let date = u8::try_from(day)
+            .ok()
+            .and_then(|dd| Date::from_calendar_date(year, month, dd).ok())
+            .ok_or(ParseError::DayOutOfRange {
+                position,
+                day,
+                month,
+                year,
+            })?;
+        match weekday {
+            Some((position, found)) if found != date.weekday() => {
+                Err(ParseError::WeekdayMismatch {
+                    position,
+                    found,
+                    expected: date.weekday(),
+                })
+            }
+            _ => Ok(date),
+        }
+    }
+
+    /// `05:25 น.` or `05.25`
+    fn time(&mut self) -> Result<Time, ParseError> {
+        let (h_position, hour, _) = self.number()?;
+        if !(self.eat(":") || self.eat(".")) {
+            let position = self.position;
+            return Err(match self.rest().chars().next() {
+                Some(found) => ParseError::BadSeparator { position, found },
+                None => ParseError::WrongTokenCount {
+                    expected: 2,
+                    found: 1,
+                },
+            });
+        }
+        let (m_position, minute, _) = self.number()?;
+        let time = time_from_pat_inner((h_position, hour), (m_position, minute))?;
+        if !self.eat("น.") {
+            self.eat("น");
+        }
+        Ok(time)
+    }
+}
+
+#[cfg(test)]
+#[rustfmt::skip]
+pub mod tests {
+
+    use super::*;
+    use crate::{clock::FixedClock, date_th, datetime_th};
+    use time::macros::{date, datetime, offset, time};
+
+    #[test]
+    fn test_datetime_from_th() {
+        assert_eq!(datetime_from_th("24 ส.ค.2521 05:25 น."), Some(datetime!(1978-08-24 05:25)));
+        assert_eq!(datetime_from_th(&datetime_th(&datetime!(2024-01-30 12:34))), Some(datetime!(2024-01-30 12:34)));
+        assert_eq!(datetime_from_th("วันพฤหัสบดีที่ 24 สิงหาคม พ.ศ. 2521 เวลา 05.25 น."), Some(datetime!(1978-08-24 05:25)));
+        assert_eq!(datetime_from_th("๒๔ ส.ค. ๒๕๒๑ ๐๕:๒๕ น."), Some(datetime!(1978-08-24 05:25)));
+        assert_eq!(datetime_from_th("24 ส.ค.2521"), None);
+    }
+
+    #[test]
+    fn test_date_from_th() {
+        assert_eq!(date_from_th(&date_th(&date!(2022-01-30))), Some(date!(2022-01-30)));
+        assert_eq!(date_from_th("30 มกราคม 2565"), Some(date!(2022-01-30)));
+        assert_eq!(date_from_th("30 ม.ค. ค.ศ. 2022"), Some(date!(2022-01-30)));
+        assert_eq!(date_from_th("อา. 30 ม.ค. 2565"), Some(date!(2022-01-30)));
+        assert_eq!(date_from_th("วันอาทิตย์, 30 ม.ค. 2565"), Some(date!(2022-01-30)));
+        assert_eq!(date_from_th("วันที่ 30 ม.ค. 2565"), Some(date!(2022-01-30)));
+        assert_eq!(date_from_th("พฤ. 24 ส.ค. 2521"), Some(date!(1978-08-24)));
+        // 2 digits year
+        let clock = FixedClock::at(datetime!(2025-06-15 09:00), offset!(+7));
+        assert_eq!(try_date_from_th_clock("30 ม.ค. 65", &clock), Ok(date!(2022-01-30)));
+        assert_eq!(try_date_from_th_clock("30 ม.ค. ค.ศ. 22", &clock), Ok(date!(2022-01-30)));
+        assert_eq!(try_datetime_from_th_clock("30 ม.ค. 99 05:25 น.", &clock), Ok(datetime!(2056-01-30 05:25)));
+        // 2 digits year by pivot
+        let today = date!(2025-06-15);
+        assert_eq!(try_date_from_th_pivot("24 ส.ค. 99", YearPivot::Past, &today), Ok(date!(1956-08-24)));
+        assert_eq!(try_date_from_th_pivot("24 ส.ค. ค.ศ. 99", YearPivot::Past, &today), Ok(date!(1999-08-24)));
+        assert_eq!(try_date_from_th_pivot("24 ส.ค. 2599", YearPivot::Past, &today), Ok(date!(2056-08-24)));
+        assert_eq!(try_datetime_from_th_pivot("24 ส.ค. 99 05:25 น.", YearPivot::Window { future: 20 }, &today), Ok(datetime!(1956-08-24 05:25)));
+        // failed
+        assert_eq!(try_date_from_th(""), Err(ParseError::Empty));
+        assert_eq!(try_date_from_th("30 มค 2565"), Err(ParseError::UnknownMonth { position: 3 }));
+        assert_eq!(try_date_from_th("30 ก.พ. 2565"), Err(ParseError::DayOutOfRange { position: 0, day: 30, month: Month::February, year: 2022 }));
+        assert_eq!(try_date_from_th("30 ก.พ. 256"), Err(ParseError::AmbiguousYear { position: 12, year: 256 }));
+        assert_eq!(try_date_from_th("จ. 30 ม.ค. 2565"), Err(ParseError::WeekdayMismatch { position: 0, found: Weekday::Monday, expected: Weekday::Sunday }));
+        assert_eq!(try_date_from_th("30 ม.ค. 2565 x"), Err(ParseError::TrailingCharacters { position: 17 }));
+    }
+
+    #[test]
+    fn test_time_from_th() {
+        assert_eq!(time_from_th("05:25 น."), Some(time!(05:25)));
+        assert_eq!(time_from_th("เวลา 5.25น"), Some(time!(05:25)));
+        assert_eq!(time_from_th("23:45"), Some(time!(23:45)));
+        assert_eq!(try_time_from_th("24:00 น."), Err(ParseError::HourOutOfRange { position: 0, hour: 24 }));
+        assert_eq!(try_time_from_th("05 25"), Err(ParseError::BadSeparator { position: 3, found: '2' }));
+    }
+}
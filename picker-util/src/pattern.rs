@@ -0,0 +1,491 @@
+//! format and parse with a pattern, `DD/MM/BBBB HH:mm` or `วันdddd ที่ D MMMM พ.ศ. BBBB`
+//!
+//! | token | value |
+//! |---|---|
+//! | `D` `DD` | day, `DD` is zero-padded |
+//! | `M` `MM` | month number, `MM` is zero-padded |
+//! | `MMM` `MMMM` | short or full month name, `ส.ค.` or `สิงหาคม` |
+//! | `BBBB` `BB` | Buddhist era year, `2568` or `68` |
+//! | `YYYY` `YY` | Common era year, `2025` or `25` |
+//! | `ddd` `dddd` | short or full weekday name, `พฤ` or `พฤหัสบดี` |
+//! | `H` `HH` | hour 0-23, `HH` is zero-padded |
+//! | `m` `mm` | minute, `mm` is zero-padded |
+//! | `s` `ss` | second, `ss` is zero-padded |
+//! | `SSS` | milliseconds |
+//!
+//! other characters are literal, `[...]` is literal too, ex. `[at] HH:mm`<br>
+//! parsing skips spaces, accepts both short and full names and 2 or 4 digits year
+
+use time::{Date, Month, PrimitiveDateTime, Time, Weekday};
+
+use crate::{
+    ParseError, YearPivot,
+    clock::default_clock,
+    locale::{Locale, Thai},
+    parse_th::{Cursor, MONTHS, WEEKDAYS},
+    time_from_pat_inner, time_with_second,
+};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Item {
+    Day {
+        pad: bool,
+    },
+    Month {
+        pad: bool,
+    },
+    MonthShort,
+    MonthFull,
+    /// `era` is added to Common era year
+    Year {
+        era: i32,
+        full: bool,
+    },
+    WeekdayShort,
+    WeekdayFull,
+    Hour {
+        pad: bool,
+    },
+    Minute {
+        pad: bool,
+    },
+    Second {
+        pad: bool,
+    },
+    Millis,
+    Literal(String),
+}
+
+/// parsed fields, `(position, value)` for error
+#[derive(Default)]
+struct Fields {
+    day: Option<(usize, u32)>,
+    month: Option<Month>,
+    year: Option<i32>,
+    weekday: Option<(usize, Weekday)>,
+    hour: Option<(usize, u32)>,
+    minute: Option<(usize, u32)>,
+    second: Option<(usize, u32)>,
+    millis: Option<u16>,
+}
+
+/// compiled pattern with locale names, see module doc for tokens
+#[derive(Clone)]
+pub struct Pattern {
+    items: Vec<Item>,
+    locale: &'static dyn Locale,
+}
+
+impl Pattern {
+    /// pattern with Thai names
+    pub fn new(pattern: &str) -> Self {
+        Self {
+            items: items(pattern),
+            locale: &Thai,
+        }
+    }
+
+    /// month and weekday names from `locale`
+    pub fn with_locale(mut self, locale: &'static dyn Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// time tokens are skipped
+    pub fn format_date(&self, date: &Date) -> String {
+        self.format(Some(date), None)
+    }
+
+    /// date tokens are skipped
+    pub fn format_time(&self, time: &Time) -> String {
+        self.format(None, Some(time))
+    }
+
+    pub fn format_datetime(&self, date_time: &PrimitiveDateTime) -> String {
+        self.format(Some(&date_time.date()), Some(&date_time.time()))
+    }
+
+    /// parse text to time::Date, 2 digits year is in current century, error will be `None`
+    pub fn parse_date(&self, text: &str) -> Option<Date> {
+        self.try_parse_date(text).ok()
+    }
+
+    /// same as `parse_date()`, error will be `ParseError`
+    pub fn try_parse_date(&self, text: &str) -> Result<Date, ParseError> {
+        let fields = self.fields(text, YearPivot::CurrentCentury, &|| {
+            default_clock().today().year()
+        })?;
+        fields.date()
+    }
+
+    /// same as `try_parse_date()`, 2 digits year is expanded by `pivot` from `today`<br>
+    /// `YY` is expanded in Common era, `Century(2000)` makes `99` 2099
+    pub fn try_parse_date_pivot(
+        &self,
+        text: &str,
+        pivot: YearPivot,
+        today: &Date,
+    ) -> Result<Date, ParseError> {
+        let fields = self.fields(text, pivot, &|| today.year())?;
+        fields.date()
+    }
+
+    /// parse text to time::Time, seconds and milliseconds are zero if not in pattern, error will be `None`
+    pub fn parse_time(&self, text: &str) -> Option<Time> {
+        self.try_parse_time(text).ok()
+    }
+
+    /// same as `parse_time()`, error will be `ParseError`
+    pub fn try_parse_time(&self, text: &str) -> Result<Time, ParseError> {
+        let fields = self.fields(text, YearPivot::CurrentCentury, &|| {
+            default_clock().today().year()
+        })?;
+        fields.time()
+    }
+
+    /// parse text to time::PrimitiveDateTime, 2 digits year is in current century, error will be `None`
+    pub fn parse_datetime(&self, text: &str) -> Option<PrimitiveDateTime> {
+        self.try_parse_datetime(text).ok()
+    }
+
+    /// same as `parse_datetime()`, error will be `ParseError`
+    pub fn try_parse_datetime(&self, text: &str) -> Result<PrimitiveDateTime, ParseError> {
+        let fields = self.fields(text, YearPivot::CurrentCentury, &|| {
+            default_clock().today().year()
+        })?;
+        Ok(PrimitiveDateTime::new(fields.date()?, fields.time()?))
+    }
+
+    /// same as `try_parse_datetime()`, 2 digits year is expanded by `pivot` from `today`
+    pub fn try_parse_datetime_pivot(
+        &self,
+        text: &str,
+        pivot: YearPivot,
+        today: &Date,
+    ) -> Result<PrimitiveDateTime, ParseError> {
+        let fields = self.fields(text, pivot, &|| today.year())?;
+        Ok(PrimitiveDateTime::new(fields.date()?, fields.time()?))
+    }
+
+    fn format(&self, date: Option<&Date>, time: Option<&Time>) -> String {
+        let mut text = String::new();
+        for item in &self.items {
+            match (item, date, time) {
+                (Item::Literal(literal), _, _) => text.push_str(literal),
+                (Item::Day { pad }, Some(d), _) => text.push_str(&number(d.day(), *pad)),
+                (Item::Month { pad }, Some(d), _) => text.push_str(&number(d.month() as u8, *pad)),
+                (Item::MonthShort, Some(d), _) => {
+                    text.push_str(self.locale.month_short(&d.month()))
+                }
+                (Item::MonthFull, Some(d), _) => text.push_str(self.locale.month_full(&d.month())),
+                (Item::Year { era, full: true }, Some(d), _) => {
+                    text.push_str(&(d.year() + era).to_string())
+                }
+                (Item::Year { era, full: false }, Some(d), _) => {
+                    text.push_str(&format!("{:0>2}", (d.year() + era).rem_euclid(100)))
+                }
+                (Item::WeekdayShort, Some(d), _) => {
+                    text.push_str(self.locale.weekday_short(&d.weekday()))
+                }
+                (Item::WeekdayFull, Some(d), _) => {
+                    text.push_str(self.locale.weekday_full(&d.weekday()))
+                }
+                (Item::Hour { pad }, _, Some(t)) => text.push_str(&number(t.hour(), *pad)),
+                (Item::Minute { pad }, _, Some(t)) => text.push_str(&number(t.minute(), *pad)),
+                (Item::Second { pad }, _, Some(t)) => text.push_str(&number(t.second(), *pad)),
+                (Item::Millis, _, Some(t)) => text.push_str(&format!("{:0>3}", t.millisecond())),
+                _ => {}
+            }
+        }
+        text
+    }
+
+    /// `today_year` is Common era, only called for 2 digits year
+    fn fields(
+        &self,
+        text: &str,
+        pivot: YearPivot,
+        today_year: &dyn Fn() -> i32,
+    ) -> Result<Fields, ParseError> {
+        let mut cursor = Cursor::new(text)?;
+        let mut fields = Fields::default();
+        let expected = self
+            .items
+            .iter()
+            .filter(|item| !matches!(item, Item::Literal(_)))
+            .count();
+        let mut found = 0;
+        for item in &self.items {
+            if let Item::Literal(literal) = item {
+                literal_pos(&mut cursor, literal)?;
+                continue;
+            }
+            if cursor.rest().is_empty() {
+                return Err(ParseError::WrongTokenCount { expected, found });
+            }
+            match item {
+                Item::Day { .. } => fields.day = Some(number_pos(&mut cursor, 2)?),
+                Item::Month { .. } => {
+                    let (position, month) = number_pos(&mut cursor, 2)?;
+                    fields.month = Some(
+                        u8::try_from(month)
+                            .ok()
+                            .and_then(|mm| Month::try_from(mm).ok())
+                            .ok_or(ParseError::MonthOutOfRange { position, month })?,
+                    );
+                }
+                Item::MonthShort | Item::MonthFull => {
+                    let position = cursor.position;
+                    let locale = self.locale;
+                    let names = MONTHS.iter().flat_map(|mm| {
+                        [(*mm, locale.month_short(mm)), (*mm, locale.month_full(mm))]
+                    });
+                    fields.month = Some(
+                        name_pos(&mut cursor, names)
+                            .ok_or(ParseError::UnknownMonth { position })?,
+                    );
+                }
+                Item::Year { era, .. } => {
+                    let (position, year, digits) = cursor.number_max(4)?;
+                    let year = year as i32;
+                    fields.year = Some(match digits {
+                        3 => return Err(ParseError::AmbiguousYear { position, year }),
+                        4 => year - era,
+                        _ => pivot.expand(year, today_year() + era) - era,
+                    });
+                }
+                Item::WeekdayShort | Item::WeekdayFull => {
+                    let position = cursor.position;
+                    let locale = self.locale;
+                    let names = WEEKDAYS.iter().flat_map(|ww| {
+                        [
+                            (*ww, locale.weekday_short(ww)),
+                            (*ww, locale.weekday_full(ww)),
+                        ]
+                    });
+                    let weekday = name_pos(&mut cursor, names)
+                        .ok_or(ParseError::UnknownWeekday { position })?;
+                    fields.weekday = Some((position, weekday));
+                }
+                Item::Hour { .. } => fields.hour = Some(number_pos(&mut cursor, 2)?),
+                Item::Minute { .. } => fields.minute = Some(number_pos(&mut cursor, 2)?),
+                Item::Second { .. } => fields.second = Some(number_pos(&mut cursor, 2)?),
+                Item::Millis => {
+                    let (_, millis, digits) = cursor.number_max(3)?;
+                    fields.millis = Some(millis as u16 * 10u16.pow(3 - digits as u32));
+                }
+                Item::Literal(_) => {}
+            }
+            found += 1;
+        }
+        cursor.end()?;
+
+        Ok(fields)
+    }
+}
+
+impl Fields {
+    /// `WrongTokenCount` if the pattern has no day, month or year
+    fn date(&self) -> Result<Date, ParseError> {
+        let (Some((position, day)), Some(month), Some(year)) = (self.day, self.month, self.year)
+        else {
+            return Err(ParseError::WrongTokenCount {
+                expected: 3,
+                found: [
+                    self.day.is_some(),
+                    self.month.is_some(),
+                    self.year.is_some(),
+                ]
+                .iter()
+                .filter(|some| **some)
+                .count(),
+            });
+        };
+        let date = u8::try_from(day)
+            .ok()
+            .and_then(|dd| Date::from_calendar_date(year, month, dd).ok())
+            .ok_or(ParseError::DayOutOfRange {
+                position,
+                day,
+                month,
+                year,
+            })?;
+        match self.weekday {
+            Some((position, found)) if found != date.weekday() => {
+                Err(ParseError::WeekdayMismatch {
+                    position,
+                    found,
+                    expected: date.weekday(),
+                })
+            }
+            _ => Ok(date),
+        }
+    }
+
+    /// `WrongTokenCount` if the pattern has no hour
+    fn time(&self) -> Result<Time, ParseError> {
+        let Some(hour) = self.hour else {
+            return Err(ParseError::WrongTokenCount {
+                expected: 1,
+                found: 0,
+            });
+        };
+        let time = time_from_pat_inner(hour, self.minute.unwrap_or((hour.0, 0)))?;
+        time_with_second(time, self.second, self.millis)
+    }
+}
+
+/// `D`, `DD`, `MMM` to items, unknown letters are literal
+fn items(pattern: &str) -> Vec<Item> {
+    let mut items = Vec::new();
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '[' {
+            let literal = chars.by_ref().take_while(|c| *c != ']').collect::<String>();
+            push_literal(&mut items, &literal);
+            continue;
+        }
+        let mut len = 1;
+        while chars.next_if_eq(&c).is_some() {
+            len += 1;
+        }
+        let item = match (c, len) {
+            ('D', 1 | 2) => Item::Day { pad: len == 2 },
+            ('M', 1 | 2) => Item::Month { pad: len == 2 },
+            ('M', 3) => Item::MonthShort,
+            ('M', 4) => Item::MonthFull,
+            ('B', 2 | 4) => Item::Year {
+                era: 543,
+                full: len == 4,
+            },
+            ('Y', 2 | 4) => Item::Year {
+                era: 0,
+                full: len == 4,
+            },
+            ('d', 3) => Item::WeekdayShort,
+            ('d', 4) => Item::WeekdayFull,
+            ('H', 1 | 2) => Item::Hour { pad: len == 2 },
+            ('m', 1 | 2) => Item::Minute { pad: len == 2 },
+            ('s', 1 | 2) => Item::Second { pad: len == 2 },
+            ('S', 3) => Item::Millis,
+            _ => {
+                push_literal(&mut items, &c.to_string().repeat(len));
+                continue;
+            }
+        };
+        items.push(item);
+    }
+    items
+}
+
+fn push_literal(items: &mut Vec<Item>, text: &str) {
+    if let Some(Item::Literal(last)) = items.last_mut() {
+        last.push_str(text);
+    } else if !text.is_empty() {
+        items.push(Item::Literal(String::from(text)));
+    }
+}
+
+fn number<T: ToString>(value: T, pad: bool) -> String {
+    let text = value.to_string();
+    if pad && text.len() < 2 {
+        ["0", &text].concat()
+    } else {
+        text
+    }
+}
+
+fn number_pos(cursor: &mut Cursor, max: usize) -> Result<(usize, u32), ParseError> {
+    let (position, value, _) = cursor.number_max(max)?;
+    Ok((position, value))
+}
+
+/// spaces are optional, literal at the end of text is optional, ex. ` น.`
+fn literal_pos(cursor: &mut Cursor, literal: &str) -> Result<(), ParseError> {
+    for c in literal.chars().filter(|c| !c.is_whitespace()) {
+        let rest = cursor.rest();
+        match rest.chars().next() {
+            None => break,
+            Some(found) if found == c => cursor.position += c.len_utf8(),
+            Some(found) => {
+                return Err(ParseError::BadSeparator {
+                    position: cursor.position,
+                    found,
+                });
+            }
+        }
+    }
+    Ok(())
+}
+
+/// the longest name at cursor, ASCII case insensitive
+fn name_pos<T, I: Iterator<Item = (T, &'static str)>>(cursor: &mut Cursor, names: I) -> Option<T> {
+    let rest = cursor.rest();
+    let (value, name) = names
+        .filter(|(_, name)| {
+            rest.get(..name.len())
+                .is_some_and(|r| r.eq_ignore_ascii_case(name))
+        })
+        .max_by_key(|(_, name)| name.len())?;
+    cursor.position += name.len();
+    Some(value)
+}
+
+#[cfg(test)]
+#[rustfmt::skip]
+pub mod tests {
+
+    use super::*;
+    use crate::locale::English;
+    use time::macros::{date, datetime, time};
+
+    #[test]
+    fn test_format() {
+        let dt = datetime!(1978-08-24 05:25:30.5);
+        assert_eq!(Pattern::new("DD/MM/BBBB HH:mm").format_datetime(&dt), String::from("24/08/2521 05:25"));
+        assert_eq!(Pattern::new("D/M/BB H:m:ss.SSS").format_datetime(&dt), String::from("24/8/21 5:25:30.500"));
+        assert_eq!(Pattern::new("D MMM BBBB").format_date(&dt.date()), String::from("24 ส.ค. 2521"));
+        assert_eq!(Pattern::new("วันdddd ที่ D MMMM พ.ศ. BBBB").format_date(&dt.date()), String::from("วันพฤหัสบดี ที่ 24 สิงหาคม พ.ศ. 2521"));
+        assert_eq!(Pattern::new("ddd D MMM YYYY").with_locale(&English).format_date(&dt.date()), String::from("Th 24 Aug 1978"));
+        assert_eq!(Pattern::new("YYYY-MM-DD[T]HH:mm").format_datetime(&dt), String::from("1978-08-24T05:25"));
+        assert_eq!(Pattern::new("HH:mm น.").format_time(&dt.time()), String::from("05:25 น."));
+    }
+
+    #[test]
+    fn test_parse() {
+        let today = date!(2025-06-15);
+        assert_eq!(Pattern::new("DD/MM/BBBB HH:mm").parse_datetime("24/08/2521 05:25"), Some(datetime!(1978-08-24 05:25)));
+        assert_eq!(Pattern::new("DD/MM/BBBB").parse_date("24/8/2521"), Some(date!(1978-08-24)));
+        assert_eq!(Pattern::new("DDMMBBBB").parse_date("24082521"), Some(date!(1978-08-24)));
+        assert_eq!(Pattern::new("D MMM BBBB").parse_date("24 สิงหาคม 2521"), Some(date!(1978-08-24)));
+        assert_eq!(Pattern::new("D MMMBBBB").parse_date("24 ส.ค.2521"), Some(date!(1978-08-24)));
+        assert_eq!(Pattern::new("วันdddd ที่ D MMMM พ.ศ. BBBB").parse_date("วันพฤหัสบดี ที่ 24 สิงหาคม พ.ศ. 2521"), Some(date!(1978-08-24)));
+        assert_eq!(Pattern::new("ddd D MMM YYYY").with_locale(&English).parse_date("th 24 aug 1978"), Some(date!(1978-08-24)));
+        assert_eq!(Pattern::new("HH:mm:ss.SSS").parse_time("05:25:30.5"), Some(time!(05:25:30.5)));
+        assert_eq!(Pattern::new("HH:mm น.").parse_time("05:25"), Some(time!(05:25)));
+        assert_eq!(Pattern::new("DD/MM/BB").try_parse_date_pivot("24/08/99", YearPivot::Past, &today), Ok(date!(1956-08-24)));
+        assert_eq!(Pattern::new("DD/MM/YY").try_parse_date_pivot("24/08/99", YearPivot::Past, &today), Ok(date!(1999-08-24)));
+        assert_eq!(Pattern::new("DD/MM/YY HH:mm").try_parse_datetime_pivot("24/08/30 13:00", YearPivot::CurrentCentury, &today), Ok(datetime!(2030-08-24 13:00)));
+        // round trip
+        let pattern = Pattern::new("วันddd D MMM BB H:mm:ss");
+        let dt = datetime!(2025-01-30 09:05:07);
+        assert_eq!(pattern.try_parse_datetime_pivot(&pattern.format_datetime(&dt), YearPivot::Past, &today), Ok(dt));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let pattern = Pattern::new("ddd DD/MM/BBBB HH:mm");
+        assert_eq!(pattern.try_parse_datetime(" "), Err(ParseError::Empty));
+        assert_eq!(pattern.try_parse_datetime("พฤ 24/08/2521"), Err(ParseError::WrongTokenCount { expected: 6, found: 4 }));
+        assert_eq!(pattern.try_parse_datetime("xx 24/08/2521 05:25"), Err(ParseError::UnknownWeekday { position: 0 }));
+        assert_eq!(pattern.try_parse_datetime("จ 24/08/2521 05:25"), Err(ParseError::WeekdayMismatch { position: 0, found: Weekday::Monday, expected: Weekday::Thursday }));
+        assert_eq!(pattern.try_parse_datetime("พฤ 24-08/2521 05:25"), Err(ParseError::BadSeparator { position: 9, found: '-' }));
+        assert_eq!(pattern.try_parse_datetime("พฤ 24/13/2521 05:25"), Err(ParseError::MonthOutOfRange { position: 10, month: 13 }));
+        assert_eq!(pattern.try_parse_datetime("พฤ 31/02/2521 05:25"), Err(ParseError::DayOutOfRange { position: 7, day: 31, month: Month::February, year: 1978 }));
+        assert_eq!(pattern.try_parse_datetime("พฤ 24/08/252 05:25"), Err(ParseError::AmbiguousYear { position: 13, year: 252 }));
+        assert_eq!(pattern.try_parse_datetime("พฤ 24/08/2521 24:25"), Err(ParseError::HourOutOfRange { position: 18, hour: 24 }));
+        assert_eq!(pattern.try_parse_datetime("พฤ 24/08/2521 05:25 x"), Err(ParseError::TrailingCharacters { position: 24 }));
+        assert_eq!(Pattern::new("D MMM BBBB").try_parse_date("24 สค 2521"), Err(ParseError::UnknownMonth { position: 3 }));
+        assert_eq!(Pattern::new("HH:mm").try_parse_date("05:25"), Err(ParseError::WrongTokenCount { expected: 3, found: 0 }));
+    }
+}
@@ -0,0 +1,327 @@
+use time::{Date, Duration, PrimitiveDateTime};
+
+use crate::{JsTime, add_months, date_th, datetime_th, month_thai, time_hm};
+
+/// start or end of a range is included or not
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Bound {
+    #[default]
+    Inclusive,
+    Exclusive,
+}
+
+/// non-empty range of days, kept as inclusive `start..=end`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DateRange {
+    start: Date,
+    end: Date,
+}
+
+impl DateRange {
+    /// `start..=end`, `None` if `end` is before `start`
+    pub fn new(start: Date, end: Date) -> Option<Self> {
+        Self::with_bounds(start, Bound::Inclusive, end, Bound::Inclusive)
+    }
+
+    /// `start..end`, `None` if no day in range
+    pub fn half_open(start: Date, end: Date) -> Option<Self> {
+        Self::with_bounds(start, Bound::Inclusive, end, Bound::Exclusive)
+    }
+
+    /// exclusive bound will be moved to the next or previous day, `None` if no day in range
+    pub fn with_bounds(
+        start: Date,
+        start_bound: Bound,
+        end: Date,
+        end_bound: Bound,
+    ) -> Option<Self> {
+        let start = match start_bound {
+            Bound::Inclusive => start,
+            Bound::Exclusive => start.next_day()?,
+        };
+        let end = match end_bound {
+            Bound::Inclusive => end,
+            Bound::Exclusive => end.previous_day()?,
+        };
+        (start <= end).then_some(Self { start, end })
+    }
+
+    /// first day
+    pub fn start(&self) -> Date {
+        self.start
+    }
+
+    /// last day
+    pub fn end(&self) -> Date {
+        self.end
+    }
+
+    /// number of days, `1` if start and end are the same day
+    pub fn len_days(&self) -> i64 {
+        (self.end - self.start).whole_days() + 1
+    }
+
+    pub fn contains(&self, date: &Date) -> bool {
+        self.start <= *date && *date <= self.end
+    }
+
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    /// days in both ranges, `None` if not overlap
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        Self::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    /// every day from start to end
+    pub fn days(&self) -> impl Iterator<Item = Date> + use<> {
+        let end = self.end;
+        std::iter::successors(Some(self.start), move |date| {
+            date.next_day().filter(|next| *next <= end)
+        })
+    }
+
+    /// one range per calendar month, first and last ranges can be partial months
+    pub fn split_by_month(&self) -> Vec<Self> {
+        let mut ranges = Vec::new();
+        let mut start = self.start;
+        while start <= self.end {
+            let month_end = add_months(&start.replace_day(1).unwrap_or(start), 1)
+                .and_then(|next_month| next_month.previous_day())
+                .unwrap_or(self.end);
+            let end = month_end.min(self.end);
+            ranges.push(Self { start, end });
+            match end.next_day() {
+                Some(next) => start = next,
+                None => break,
+            }
+        }
+        ranges
+    }
+
+    fn is_whole_months(&self) -> bool {
+        self.start.day() == 1 && self.end.next_day().is_none_or(|next| next.day() == 1)
+    }
+}
+
+/// range of datetimes with inclusive or exclusive bounds
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DateTimeRange {
+    pub start: PrimitiveDateTime,
+    pub start_bound: Bound,
+    pub end: PrimitiveDateTime,
+    pub end_bound: Bound,
+}
+
+impl DateTimeRange {
+    /// `start..=end`, `None` if `end` is before `start`
+    pub fn new(start: PrimitiveDateTime, end: PrimitiveDateTime) -> Option<Self> {
+        Self::with_bounds(start, Bound::Inclusive, end, Bound::Inclusive)
+    }
+
+    /// `start..end`, `None` if `end` is not after `start`
+    pub fn half_open(start: PrimitiveDateTime, end: PrimitiveDateTime) -> Option<Self> {
+        Self::with_bounds(start, Bound::Inclusive, end, Bound::Exclusive)
+    }
+
+    /// `None` if no datetime in range
+    pub fn with_bounds(
+        start: PrimitiveDateTime,
+        start_bound: Bound,
+        end: PrimitiveDateTime,
+        end_bound: Bound,
+    ) -> Option<Self> {
+        let range = Self {
+            start,
+            start_bound,
+            end,
+            end_bound,
+        };
+        (!range.is_empty()).then_some(range)
+    }
+
+    pub fn duration(&self) -> Duration {
+        self.end - self.start
+    }
+
+    pub fn contains(&self, date_time: &PrimitiveDateTime) -> bool {
+        let after_start = match self.start_bound {
+            Bound::Inclusive => self.start <= *date_time,
+            Bound::Exclusive => self.start < *date_time,
+        };
+        let before_end = match self.end_bound {
+            Bound::Inclusive => *date_time <= self.end,
+            Bound::Exclusive => *date_time < self.end,
+        };
+        after_start && before_end
+    }
+
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// datetimes in both ranges, `None` if not overlap
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        // later start, exclusive wins on tie
+        let (start, start_bound) = if self.start == other.start {
+            (
+                self.start,
+                exclusive_if_any(self.start_bound, other.start_bound),
+            )
+        } else if self.start > other.start {
+            (self.start, self.start_bound)
+        } else {
+            (other.start, other.start_bound)
+        };
+        // earlier end, exclusive wins on tie
+        let (end, end_bound) = if self.end == other.end {
+            (self.end, exclusive_if_any(self.end_bound, other.end_bound))
+        } else if self.end < other.end {
+            (self.end, self.end_bound)
+        } else {
+            (other.end, other.end_bound)
+        };
+        Self::with_bounds(start, start_bound, end, end_bound)
+    }
+
+    fn is_empty(&self) -> bool {
+        match (self.start_bound, self.end_bound) {
+            (Bound::Inclusive, Bound::Inclusive) => self.start > self.end,
+            _ => self.start >= self.end,
+        }
+    }
+}
+
+fn exclusive_if_any(a: Bound, b: Bound) -> Bound {
+    if a == Bound::Exclusive || b == Bound::Exclusive {
+        Bound::Exclusive
+    } else {
+        Bound::Inclusive
+    }
+}
+
+/// format DateRange to compact Thai string
+/// - `5 ส.ค.2568` one day
+/// - `1–5 ส.ค.2568` same month
+/// - `28 ก.ค.–3 ส.ค.2568` same year
+/// - `28 ธ.ค.2567–3 ม.ค.2568` different years
+/// - `ส.ค.2568`, `ก.ค.–ส.ค.2568` or `ธ.ค.2567–ม.ค.2568` whole months
+pub fn date_range_th(range: &DateRange) -> String {
+    let (start, end) = (range.start, range.end);
+    let same_year = start.year() == end.year();
+    let same_month = same_year && start.month() == end.month();
+    if range.is_whole_months() {
+        let end_text = [month_thai(&end.month()), &(end.year() + 543).to_string()].concat();
+        if same_month {
+            end_text
+        } else if same_year {
+            [month_thai(&start.month()), "–", &end_text].concat()
+        } else {
+            let start_text = [
+                month_thai(&start.month()),
+                &(start.year() + 543).to_string(),
+            ]
+            .concat();
+            [start_text, end_text].join("–")
+        }
+    } else if start == end {
+        date_th(&start)
+    } else if same_month {
+        [start.day().to_string(), date_th(&end)].join("–")
+    } else if same_year {
+        format!(
+            "{} {}–{}",
+            start.day(),
+            month_thai(&start.month()),
+            date_th(&end)
+        )
+    } else {
+        [date_th(&start), date_th(&end)].join("–")
+    }
+}
+
+/// format DateTimeRange to Thai string, `5 ส.ค.2568 09:00–12:00 น.` on the same day
+/// or `5 ส.ค.2568 09:00 น.–6 ส.ค.2568 12:00 น.`
+pub fn datetime_range_th(range: &DateTimeRange) -> String {
+    if range.start.date() == range.end.date() {
+        format!(
+            "{} {}–{}",
+            date_th(&range.start.date()),
+            range.start.time().js_string(),
+            time_hm(&range.end.time())
+        )
+    } else {
+        [datetime_th(&range.start), datetime_th(&range.end)].join("–")
+    }
+}
+
+#[cfg(test)]
+#[rustfmt::skip]
+pub mod tests {
+
+    use super::*;
+    use time::macros::{date, datetime};
+
+    #[test]
+    fn test_date_range() {
+        let range = DateRange::new(date!(2025-07-28), date!(2025-08-03)).unwrap();
+        assert_eq!(range.len_days(), 7);
+        assert!(range.contains(&date!(2025-08-03)));
+        assert!(!range.contains(&date!(2025-08-04)));
+        assert_eq!(DateRange::half_open(date!(2025-07-28), date!(2025-08-04)), Some(range));
+        assert_eq!(DateRange::with_bounds(date!(2025-07-27), Bound::Exclusive, date!(2025-08-03), Bound::Inclusive), Some(range));
+        assert_eq!(DateRange::new(date!(2025-08-03), date!(2025-08-02)), None);
+        assert_eq!(DateRange::half_open(date!(2025-08-03), date!(2025-08-03)), None);
+        let other = DateRange::new(date!(2025-08-03), date!(2025-08-10)).unwrap();
+        assert!(range.overlaps(&other));
+        assert_eq!(range.intersection(&other), DateRange::new(date!(2025-08-03), date!(2025-08-03)));
+        let other = DateRange::new(date!(2025-08-04), date!(2025-08-10)).unwrap();
+        assert!(!range.overlaps(&other));
+        assert_eq!(range.intersection(&other), None);
+        assert_eq!(range.days().count(), 7);
+        assert_eq!(range.days().last(), Some(date!(2025-08-03)));
+    }
+
+    #[test]
+    fn test_split_by_month() {
+        let range = DateRange::new(date!(2024-12-15), date!(2025-02-10)).unwrap();
+        assert_eq!(range.split_by_month(), vec![
+            DateRange::new(date!(2024-12-15), date!(2024-12-31)).unwrap(),
+            DateRange::new(date!(2025-01-01), date!(2025-01-31)).unwrap(),
+            DateRange::new(date!(2025-02-01), date!(2025-02-10)).unwrap(),
+        ]);
+        let range = DateRange::new(date!(2025-08-01), date!(2025-08-05)).unwrap();
+        assert_eq!(range.split_by_month(), vec![range]);
+    }
+
+    #[test]
+    fn test_datetime_range() {
+        let range = DateTimeRange::half_open(datetime!(2025-08-05 09:00), datetime!(2025-08-05 12:00)).unwrap();
+        assert_eq!(range.duration(), Duration::hours(3));
+        assert!(range.contains(&datetime!(2025-08-05 09:00)));
+        assert!(!range.contains(&datetime!(2025-08-05 12:00)));
+        let next = DateTimeRange::half_open(datetime!(2025-08-05 12:00), datetime!(2025-08-05 13:00)).unwrap();
+        assert!(!range.overlaps(&next));
+        let next = DateTimeRange::new(datetime!(2025-08-05 11:00), datetime!(2025-08-05 13:00)).unwrap();
+        assert_eq!(range.intersection(&next), DateTimeRange::half_open(datetime!(2025-08-05 11:00), datetime!(2025-08-05 12:00)));
+        assert_eq!(DateTimeRange::half_open(datetime!(2025-08-05 12:00), datetime!(2025-08-05 12:00)), None);
+        assert!(DateTimeRange::new(datetime!(2025-08-05 12:00), datetime!(2025-08-05 12:00)).is_some());
+    }
+
+    #[test]
+    fn test_range_th() {
+        let th = |start, end| date_range_th(&DateRange::new(start, end).unwrap());
+        assert_eq!(th(date!(2025-08-05), date!(2025-08-05)), String::from("5 ส.ค.2568"));
+        assert_eq!(th(date!(2025-08-01), date!(2025-08-05)), String::from("1–5 ส.ค.2568"));
+        assert_eq!(th(date!(2025-07-28), date!(2025-08-03)), String::from("28 ก.ค.–3 ส.ค.2568"));
+        assert_eq!(th(date!(2024-12-28), date!(2025-01-03)), String::from("28 ธ.ค.2567–3 ม.ค.2568"));
+        assert_eq!(th(date!(2024-12-01), date!(2025-01-31)), String::from("ธ.ค.2567–ม.ค.2568"));
+        assert_eq!(th(date!(2025-07-01), date!(2025-08-31)), String::from("ก.ค.–ส.ค.2568"));
+        assert_eq!(th(date!(2025-08-01), date!(2025-08-31)), String::from("ส.ค.2568"));
+        let range = DateTimeRange::new(datetime!(2025-08-05 09:00), datetime!(2025-08-05 12:00)).unwrap();
+        assert_eq!(datetime_range_th(&range), String::from("5 ส.ค.2568 09:00–12:00 น."));
+        let range = DateTimeRange::new(datetime!(2025-08-05 09:00), datetime!(2025-08-06 12:00)).unwrap();
+        assert_eq!(datetime_range_th(&range), String::from("5 ส.ค.2568 09:00 น.–6 ส.ค.2568 12:00 น."));
+    }
+}
@@ -0,0 +1,211 @@
+//! every plausible reading of an ambiguous pat input, best first
+//!
+//! ```ignore
+//! time_readings_from_pat("235"); // [02:35 (60), 23:05 (40)]
+//! date_readings_from_pat("05/08/2568"); // [5 ส.ค. 2568 (80), 8 พ.ค. 2568 (20)]
+//! ```
+//! the first reading is always what `try_*_from_pat` returns, confidences of an input sum to 100
+
+use std::cmp::Reverse;
+
+use time::{Date, PrimitiveDateTime, Time};
+
+use crate::{
+    ParseError, Precision, YearPivot, clock::default_clock, current_century, date_from_pat_inner,
+    date_parts_from_pat, digits, digits_pos, part_error, split_pos, time_from_pat_inner,
+    try_time_from_pat_with,
+};
+
+/// one way to read a typed input, `confidence` is a percentage
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Reading<T> {
+    pub value: T,
+    pub confidence: u8,
+}
+
+impl<T> Reading<T> {
+    pub fn new(value: T, confidence: u8) -> Self {
+        Self { value, confidence }
+    }
+
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Reading<U> {
+        Reading::new(f(self.value), self.confidence)
+    }
+}
+
+/// `primary` with its `alternative` if it is a different value, `split` is the confidence of `primary`
+fn ranked<T: PartialEq>(primary: T, alternative: Option<T>, split: u8) -> Vec<Reading<T>> {
+    match alternative.filter(|alt| *alt != primary) {
+        Some(alt) => vec![Reading::new(primary, split), Reading::new(alt, 100 - split)],
+        None => vec![Reading::new(primary, 100)],
+    }
+}
+
+/// readings of Buddhism `DD/MM/YYYY`, swapped `MM/DD/YYYY` is the alternative, error will be empty
+pub fn date_readings_from_pat(text: &str) -> Vec<Reading<Date>> {
+    date_readings_expand(text, &current_century(default_clock())).unwrap_or_default()
+}
+/// same as `date_readings_from_pat()`, 2 digits year is expanded by `pivot` from `today`, error will be `ParseError`
+pub fn try_date_readings_from_pat_pivot(
+    text: &str,
+    pivot: YearPivot,
+    today: &Date,
+) -> Result<Vec<Reading<Date>>, ParseError> {
+    date_readings_expand(text, &|y| pivot.expand(y, today.year() + 543))
+}
+fn date_readings_expand(
+    text: &str,
+    expand: &dyn Fn(i32) -> i32,
+) -> Result<Vec<Reading<Date>>, ParseError> {
+    let (d, m, y) = date_parts_from_pat(text, expand)?;
+    let primary = date_from_pat_inner(d, m, y)?;
+    // Thai order `DD/MM` is far more likely than `MM/DD`
+    Ok(ranked(primary, date_from_pat_inner(m, d, y).ok(), 80))
+}
+
+/// readings of `HHMM` or `HH:MM`, `235` is `02:35` or `23:05`, error will be empty
+pub fn time_readings_from_pat(text: &str) -> Vec<Reading<Time>> {
+    try_time_readings_from_pat_with(text, Precision::Minutes).unwrap_or_default()
+}
+/// same as `time_readings_from_pat()`, keep seconds or milliseconds by `precision`, error will be `ParseError`<br>
+/// only 2 and 3 digits without separator have an alternative
+pub fn try_time_readings_from_pat_with(
+    text: &str,
+    precision: Precision,
+) -> Result<Vec<Reading<Time>>, ParseError> {
+    let primary = try_time_from_pat_with(text, precision)?;
+    if text.contains(['/', '-', '.', ':']) {
+        return Ok(ranked(primary, None, 100));
+    }
+    let c = digits(text);
+    let read = |h: &[(usize, char)], m: Option<&[(usize, char)]>| {
+        let m = match m {
+            Some(m) => digits_pos(m).ok()?,
+            None => (h[0].0, 0),
+        };
+        time_from_pat_inner(digits_pos(h).ok()?, m).ok()
+    };
+    Ok(match c.len() {
+        // `H:MM` or `HH:M`, either is as likely
+        3 => {
+            let alternative = [
+                read(&c[0..1], Some(&c[1..3])),
+                read(&c[0..2], Some(&c[2..3])),
+            ];
+            ranked(
+                primary,
+                alternative.into_iter().flatten().find(|t| *t != primary),
+                60,
+            )
+        }
+        // `HH:00` or `H:0M`
+        2 => {
+            let alternative = [read(&c[0..2], None), read(&c[0..1], Some(&c[1..2]))];
+            ranked(
+                primary,
+                alternative.into_iter().flatten().find(|t| *t != primary),
+                80,
+            )
+        }
+        _ => ranked(primary, None, 100),
+    })
+}
+
+/// readings of Buddhism `DD/MM/YYYY HH:MM`, every date reading with every time reading, error will be empty
+pub fn datetime_readings_from_pat(text: &str) -> Vec<Reading<PrimitiveDateTime>> {
+    datetime_readings_expand(text, Precision::Minutes, &current_century(default_clock()))
+        .unwrap_or_default()
+}
+/// same as `datetime_readings_from_pat()`, keep seconds or milliseconds by `precision`,
+/// 2 digits year is expanded by `pivot` from `today`, error will be `ParseError`
+pub fn try_datetime_readings_from_pat_pivot(
+    text: &str,
+    precision: Precision,
+    pivot: YearPivot,
+    today: &Date,
+) -> Result<Vec<Reading<PrimitiveDateTime>>, ParseError> {
+    datetime_readings_expand(text, precision, &|y| pivot.expand(y, today.year() + 543))
+}
+fn datetime_readings_expand(
+    text: &str,
+    precision: Precision,
+    expand: &dyn Fn(i32) -> i32,
+) -> Result<Vec<Reading<PrimitiveDateTime>>, ParseError> {
+    if text.trim().is_empty() {
+        return Err(ParseError::Empty);
+    }
+    let dt = split_pos(text, &[' ']);
+    if dt.len() != 2 {
+        return Err(ParseError::WrongTokenCount {
+            expected: 2,
+            found: dt.len(),
+        });
+    }
+    let dates = date_readings_expand(dt[0].1, expand).map_err(|e| part_error(e, dt[0].0))?;
+    let times =
+        try_time_readings_from_pat_with(dt[1].1, precision).map_err(|e| part_error(e, dt[1].0))?;
+    let mut readings: Vec<_> = dates
+        .iter()
+        .flat_map(|d| {
+            times.iter().map(|t| {
+                Reading::new(
+                    PrimitiveDateTime::new(d.value, t.value),
+                    (d.confidence as u16 * t.confidence as u16 / 100) as u8,
+                )
+            })
+        })
+        .collect();
+    // stable, ties keep the date reading order
+    readings.sort_by_key(|r| Reverse(r.confidence));
+    Ok(readings)
+}
+
+#[cfg(test)]
+#[rustfmt::skip]
+pub mod tests {
+
+    use super::*;
+    use crate::{try_date_from_pat, try_datetime_from_pat};
+    use time::macros::{date, datetime, time};
+
+    #[test]
+    fn test_date_readings() {
+        assert_eq!(date_readings_from_pat("05/08/2568"), vec![Reading::new(date!(2025-08-05), 80), Reading::new(date!(2025-05-08), 20)]);
+        assert_eq!(date_readings_from_pat("08/08/2568"), vec![Reading::new(date!(2025-08-08), 100)]);
+        assert_eq!(date_readings_from_pat("24/08/2568"), vec![Reading::new(date!(2025-08-24), 100)]);
+        // convertable MMDDYYYY, the only valid reading
+        assert_eq!(date_readings_from_pat("11/13/2565"), vec![Reading::new(date!(2022-11-13), 100)]);
+        assert_eq!(date_readings_from_pat("31/02/2568"), vec![]);
+        assert_eq!(try_date_readings_from_pat_pivot("", YearPivot::CurrentCentury, &date!(2025-08-24)), Err(ParseError::Empty));
+        assert_eq!(try_date_readings_from_pat_pivot("050868", YearPivot::CurrentCentury, &date!(2025-08-24)).map(|r| r.len()), Ok(2));
+    }
+
+    #[test]
+    fn test_time_readings() {
+        assert_eq!(time_readings_from_pat("235"), vec![Reading::new(time!(02:35), 60), Reading::new(time!(23:05), 40)]);
+        assert_eq!(time_readings_from_pat("161"), vec![Reading::new(time!(16:01), 100)]);
+        assert_eq!(time_readings_from_pat("066"), vec![Reading::new(time!(06:06), 100)]);
+        assert_eq!(time_readings_from_pat("12"), vec![Reading::new(time!(12:00), 80), Reading::new(time!(01:02), 20)]);
+        assert_eq!(time_readings_from_pat("05"), vec![Reading::new(time!(00:05), 80), Reading::new(time!(05:00), 20)]);
+        assert_eq!(time_readings_from_pat("35"), vec![Reading::new(time!(03:05), 100)]);
+        assert_eq!(time_readings_from_pat("2:35"), vec![Reading::new(time!(02:35), 100)]);
+        assert_eq!(time_readings_from_pat("1234"), vec![Reading::new(time!(12:34), 100)]);
+        assert_eq!(try_time_readings_from_pat_with("2460", Precision::Minutes), Err(ParseError::HourOutOfRange { position: 0, hour: 24 }));
+    }
+
+    #[test]
+    fn test_datetime_readings() {
+        let readings = datetime_readings_from_pat("05/08/2568 235");
+        assert_eq!(readings.iter().map(|r| r.confidence).collect::<Vec<_>>(), vec![48, 32, 12, 8]);
+        assert_eq!(readings[0].value, datetime!(2025-08-05 02:35));
+        assert_eq!(readings[1].value, datetime!(2025-08-05 23:05));
+        assert_eq!(readings[2].value, datetime!(2025-05-08 02:35));
+        assert_eq!(datetime_readings_from_pat("24/08/2568 12:34"), vec![Reading::new(datetime!(2025-08-24 12:34), 100)]);
+        assert_eq!(try_datetime_readings_from_pat_pivot("24/08/2568", Precision::Minutes, YearPivot::CurrentCentury, &date!(2025-08-24)), Err(ParseError::WrongTokenCount { expected: 2, found: 1 }));
+        // first reading is the parsed value
+        for text in ["05/08/2568 235", "11/13/2565 12", "240868 0900", "1/2/68 160"] {
+            assert_eq!(datetime_readings_from_pat(text).first().map(|r| r.value), try_datetime_from_pat(text).ok());
+            assert_eq!(date_readings_from_pat(&text[..text.find(' ').unwrap()]).first().map(|r| r.value), try_date_from_pat(&text[..text.find(' ').unwrap()]).ok());
+        }
+    }
+}
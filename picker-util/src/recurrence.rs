@@ -0,0 +1,743 @@
+//! RFC 5545 recurrence subset, `FREQ`, `INTERVAL`, `BYDAY`, `BYMONTHDAY`, `COUNT`, `UNTIL`
+//! and `EXDATE`
+//!
+//! ```ignore
+//! let session: Recurrence = "DTSTART:20250805T083000\nRRULE:FREQ=MONTHLY;BYDAY=2TU".parse()?;
+//! recurrence_th(&session); // ทุกวันอังคารที่ 2 ของเดือน เวลา 08:30 น.
+//! session.occurrences(&range); // [2025-08-12 8:30, 2025-09-09 8:30, ...]
+//! ```
+//! `WKST` is always Monday, `YEARLY` repeats in the month of `DTSTART` as `BYMONTH` is not supported
+
+use std::{fmt, str::FromStr};
+
+use time::{
+    Date, Duration, PrimitiveDateTime, Time, Weekday,
+    macros::{format_description, time},
+};
+
+use crate::{
+    ParseError, add_months, date_th, default_offset, range::DateRange, time_hm, week::week_start,
+    weekday_thai_full,
+};
+
+const WEEKDAY_CODES: [(&str, Weekday); 7] = [
+    ("SU", Weekday::Sunday),
+    ("MO", Weekday::Monday),
+    ("TU", Weekday::Tuesday),
+    ("WE", Weekday::Wednesday),
+    ("TH", Weekday::Thursday),
+    ("FR", Weekday::Friday),
+    ("SA", Weekday::Saturday),
+];
+
+const WORKING_DAYS: [Weekday; 5] = [
+    Weekday::Monday,
+    Weekday::Tuesday,
+    Weekday::Wednesday,
+    Weekday::Thursday,
+    Weekday::Friday,
+];
+
+/// `FREQ`, length of a period
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+const FREQUENCIES: [Frequency; 4] = [
+    Frequency::Daily,
+    Frequency::Weekly,
+    Frequency::Monthly,
+    Frequency::Yearly,
+];
+
+impl Frequency {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Daily => "DAILY",
+            Self::Weekly => "WEEKLY",
+            Self::Monthly => "MONTHLY",
+            Self::Yearly => "YEARLY",
+        }
+    }
+
+    fn unit_th(&self) -> &'static str {
+        match self {
+            Self::Daily => "วัน",
+            Self::Weekly => "สัปดาห์",
+            Self::Monthly => "เดือน",
+            Self::Yearly => "ปี",
+        }
+    }
+}
+
+/// weekday of `BYDAY`, `ordinal` is n-th of the month, negative counts from the end<br>
+/// `2TU` is `ByDay::nth(2, Weekday::Tuesday)`, `-1FR` is `ByDay::last(Weekday::Friday)`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ByDay {
+    pub ordinal: Option<i8>,
+    pub weekday: Weekday,
+}
+
+impl ByDay {
+    /// every `weekday`, `TU`
+    pub fn every(weekday: Weekday) -> Self {
+        Self {
+            ordinal: None,
+            weekday,
+        }
+    }
+
+    /// n-th `weekday` of the month, `2TU`
+    pub fn nth(ordinal: i8, weekday: Weekday) -> Self {
+        Self {
+            ordinal: Some(ordinal),
+            weekday,
+        }
+    }
+
+    /// last `weekday` of the month, `-1FR`
+    pub fn last(weekday: Weekday) -> Self {
+        Self::nth(-1, weekday)
+    }
+
+    fn matches(&self, date: &Date) -> bool {
+        let day = date.day() as i8;
+        let length = date.month().length(date.year()) as i8;
+        date.weekday() == self.weekday
+            && match self.ordinal {
+                None => true,
+                Some(n) if n > 0 => (day - 1) / 7 + 1 == n,
+                Some(n) => (length - day) / 7 + 1 == -n,
+            }
+    }
+
+    /// `วันอังคารที่ 2`, `วันศุกร์สุดท้าย`, `วันศุกร์ที่ 2 จากท้าย`
+    fn th(&self) -> String {
+        let weekday = ["วัน", weekday_thai_full(&self.weekday)].concat();
+        match self.ordinal {
+            None => weekday,
+            Some(-1) => [weekday, "สุดท้าย".into()].concat(),
+            Some(n) if n < 0 => format!("{weekday}ที่ {} จากท้าย", -n),
+            Some(n) => format!("{weekday}ที่ {n}"),
+        }
+    }
+}
+
+impl fmt::Display for ByDay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let code = WEEKDAY_CODES
+            .iter()
+            .find(|(_, weekday)| *weekday == self.weekday)
+            .map_or("", |(code, _)| code);
+        match self.ordinal {
+            Some(ordinal) => write!(f, "{ordinal}{code}"),
+            None => f.write_str(code),
+        }
+    }
+}
+
+/// `RRULE` value, ex. `FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,WE`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RecurrenceRule {
+    pub frequency: Frequency,
+    /// every n-th period, `0` is the same as `1`
+    pub interval: u32,
+    /// empty is the weekday of `DTSTART` in `WEEKLY`
+    pub by_day: Vec<ByDay>,
+    /// 1 to 31 or -1 to -31 from the end of month, empty is the day of `DTSTART` in `MONTHLY` and `YEARLY`
+    pub by_month_day: Vec<i8>,
+    /// number of occurrences including `EXDATE`
+    pub count: Option<u32>,
+    /// last possible occurrence, inclusive
+    pub until: Option<PrimitiveDateTime>,
+}
+
+impl RecurrenceRule {
+    /// every period from `DTSTART`, without limit
+    pub fn new(frequency: Frequency) -> Self {
+        Self {
+            frequency,
+            interval: 1,
+            by_day: Vec::new(),
+            by_month_day: Vec::new(),
+            count: None,
+            until: None,
+        }
+    }
+
+    /// first day and number of days of n-th period from `start`
+    fn period(&self, start: &Date, period: i64) -> Option<(Date, u8)> {
+        let step = period.checked_mul(self.interval.max(1) as i64)?;
+        match self.frequency {
+            Frequency::Daily => Some((start.checked_add(Duration::days(step))?, 1)),
+            Frequency::Weekly => Some((
+                week_start(start, Weekday::Monday)?.checked_add(Duration::weeks(step))?,
+                7,
+            )),
+            Frequency::Monthly => {
+                let first = add_months(&start.replace_day(1).ok()?, step)?;
+                Some((first, first.month().length(first.year())))
+            }
+            Frequency::Yearly => {
+                let year = start.year().checked_add(i32::try_from(step).ok()?)?;
+                let first = Date::from_calendar_date(year, start.month(), 1).ok()?;
+                Some((first, first.month().length(year)))
+            }
+        }
+    }
+
+    fn matches(&self, date: &Date, start: &Date) -> bool {
+        let weekday = if self.by_day.is_empty() {
+            self.frequency != Frequency::Weekly || date.weekday() == start.weekday()
+        } else {
+            self.by_day.iter().any(|by_day| by_day.matches(date))
+        };
+        let length = date.month().length(date.year()) as i8;
+        let month_day = if self.by_month_day.is_empty() {
+            !matches!(self.frequency, Frequency::Monthly | Frequency::Yearly)
+                || !self.by_day.is_empty()
+                || date.day() == start.day()
+        } else {
+            self.by_month_day.iter().any(|day| match *day {
+                day if day > 0 => date.day() as i8 == day,
+                day => (length + 1 + day) == date.day() as i8,
+            })
+        };
+        weekday && month_day
+    }
+}
+
+impl fmt::Display for RecurrenceRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "FREQ={}", self.frequency.code())?;
+        if self.interval > 1 {
+            write!(f, ";INTERVAL={}", self.interval)?;
+        }
+        if !self.by_day.is_empty() {
+            let days = self.by_day.iter().map(ByDay::to_string).collect::<Vec<_>>();
+            write!(f, ";BYDAY={}", days.join(","))?;
+        }
+        if !self.by_month_day.is_empty() {
+            let days = self
+                .by_month_day
+                .iter()
+                .map(i8::to_string)
+                .collect::<Vec<_>>();
+            write!(f, ";BYMONTHDAY={}", days.join(","))?;
+        }
+        if let Some(count) = self.count {
+            write!(f, ";COUNT={count}")?;
+        }
+        if let Some(until) = self.until {
+            write!(f, ";UNTIL={}", basic_datetime(&until))?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for RecurrenceRule {
+    type Err = ParseError;
+
+    /// `FREQ=MONTHLY;BYDAY=2TU` with optional `RRULE:` prefix, names are case insensitive
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let text = text.trim_end();
+        let offset = match text.get(..6) {
+            Some(prefix) if prefix.eq_ignore_ascii_case("RRULE:") => 6,
+            _ => 0,
+        };
+        if text[offset..].trim().is_empty() {
+            return Err(ParseError::Empty);
+        }
+        let mut frequency = None;
+        let mut rule = Self::new(Frequency::Daily);
+        let mut by_day_position = None;
+        let mut position = offset;
+        for part in text[offset..].split(';') {
+            let part_position = position;
+            position += part.len() + 1;
+            if part.is_empty() {
+                continue;
+            }
+            let invalid = ParseError::InvalidRule {
+                position: part_position,
+            };
+            let (name, value) = part.split_once('=').ok_or(invalid)?;
+            let value_position = part_position + name.len() + 1;
+            match name.trim().to_ascii_uppercase().as_str() {
+                "FREQ" => {
+                    frequency = FREQUENCIES
+                        .into_iter()
+                        .find(|frequency| frequency.code().eq_ignore_ascii_case(value));
+                    if frequency.is_none() {
+                        return Err(ParseError::InvalidRule {
+                            position: value_position,
+                        });
+                    }
+                }
+                "INTERVAL" => rule.interval = parse_count(value, value_position)?,
+                "BYDAY" => {
+                    rule.by_day = parse_list(value, value_position, parse_by_day)?;
+                    by_day_position = Some(part_position);
+                }
+                "BYMONTHDAY" => {
+                    rule.by_month_day = parse_list(value, value_position, parse_month_day)?
+                }
+                "COUNT" => rule.count = Some(parse_count(value, value_position)?),
+                "UNTIL" => {
+                    let (date, time) = parse_basic(value, value_position)?;
+                    rule.until = Some(PrimitiveDateTime::new(
+                        date,
+                        time.unwrap_or(time!(23:59:59)),
+                    ));
+                }
+                "WKST" if value.eq_ignore_ascii_case("MO") => {}
+                _ => return Err(invalid),
+            }
+        }
+        rule.frequency = frequency.ok_or(ParseError::InvalidRule { position: offset })?;
+        if rule.count.is_some() && rule.until.is_some() {
+            return Err(ParseError::InvalidRule { position: offset });
+        }
+        // n-th weekday needs MONTHLY or YEARLY
+        if let Some(position) = by_day_position
+            && matches!(rule.frequency, Frequency::Daily | Frequency::Weekly)
+            && rule.by_day.iter().any(|by_day| by_day.ordinal.is_some())
+        {
+            return Err(ParseError::InvalidRule { position });
+        }
+        Ok(rule)
+    }
+}
+
+/// `DTSTART`, `RRULE` and `EXDATE` lines of a recurring event at default offset
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Recurrence {
+    /// first possible occurrence, its time is the time of every occurrence
+    pub start: PrimitiveDateTime,
+    pub rule: RecurrenceRule,
+    /// cancelled occurrences
+    pub exdates: Vec<PrimitiveDateTime>,
+}
+
+impl Recurrence {
+    pub fn new(start: PrimitiveDateTime, rule: RecurrenceRule) -> Self {
+        Self {
+            start,
+            rule,
+            exdates: Vec::new(),
+        }
+    }
+
+    /// all occurrences from `start` in order, endless without `COUNT` or `UNTIL`<br>
+    /// `start` is an occurrence only when it matches the rule
+    pub fn iter(&self) -> impl Iterator<Item = PrimitiveDateTime> + '_ {
+        self.iter_to(self.rule.until.map(|until| until.date()))
+    }
+    /// same as `iter()` without periods starting after `last`,
+    /// a rule that never matches ends there instead of walking to `Date::MAX`
+    fn iter_to(&self, last: Option<Date>) -> impl Iterator<Item = PrimitiveDateTime> + '_ {
+        let start = self.start.date();
+        let rule = &self.rule;
+        (0..)
+            .map_while(move |period| rule.period(&start, period))
+            .take_while(move |(first, _)| last.is_none_or(|last| *first <= last))
+            .flat_map(move |(first, days)| {
+                (0..days as i64).filter_map(move |day| first.checked_add(Duration::days(day)))
+            })
+            .filter(move |date| *date >= start && rule.matches(date, &start))
+            .map(|date| PrimitiveDateTime::new(date, self.start.time()))
+            .take_while(|date_time| rule.until.is_none_or(|until| *date_time <= until))
+            .take(rule.count.map_or(usize::MAX, |count| count as usize))
+            .filter(|date_time| !self.exdates.contains(date_time))
+    }
+
+    /// occurrences on days of `range`
+    pub fn occurrences(&self, range: &DateRange) -> Vec<PrimitiveDateTime> {
+        let last = self
+            .rule
+            .until
+            .map_or(range.end(), |until| until.date().min(range.end()));
+        self.iter_to(Some(last))
+            .skip_while(|date_time| date_time.date() < range.start())
+            .take_while(|date_time| date_time.date() <= range.end())
+            .collect()
+    }
+}
+
+impl fmt::Display for Recurrence {
+    /// lines are separated by `\r\n` as in iCalendar
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "DTSTART:{}\r\nRRULE:{}",
+            basic_datetime(&self.start),
+            self.rule
+        )?;
+        if !self.exdates.is_empty() {
+            let dates = self.exdates.iter().map(basic_datetime).collect::<Vec<_>>();
+            write!(f, "\r\nEXDATE:{}", dates.join(","))?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Recurrence {
+    type Err = ParseError;
+
+    /// `DTSTART`, `RRULE` and optional `EXDATE` lines, parameters as `;TZID=` are ignored<br>
+    /// date only `EXDATE` is at the time of `DTSTART`
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        if text.trim().is_empty() {
+            return Err(ParseError::Empty);
+        }
+        let mut start = None;
+        let mut rule = None;
+        let mut exdates = Vec::new();
+        let mut position = 0;
+        for line in text.split('\n') {
+            let line_position = position;
+            position += line.len() + 1;
+            let line = line.trim_end();
+            if line.is_empty() {
+                continue;
+            }
+            let invalid = ParseError::InvalidRule {
+                position: line_position,
+            };
+            let (name, value) = line.split_once(':').ok_or(invalid)?;
+            let value_position = line_position + name.len() + 1;
+            let name = name.split(';').next().unwrap_or_default();
+            match name.to_ascii_uppercase().as_str() {
+                "DTSTART" => start = Some(parse_basic(value, value_position)?),
+                "RRULE" => {
+                    rule = Some(
+                        value
+                            .parse()
+                            .map_err(|e: ParseError| e.offset(value_position))?,
+                    )
+                }
+                "EXDATE" => exdates.extend(parse_list(value, value_position, parse_basic)?),
+                _ => return Err(invalid),
+            }
+        }
+        let (date, time) = start.ok_or(ParseError::InvalidRule { position: 0 })?;
+        let start = PrimitiveDateTime::new(date, time.unwrap_or(Time::MIDNIGHT));
+        let rule = rule.ok_or(ParseError::InvalidRule { position: 0 })?;
+        let exdates = exdates
+            .into_iter()
+            .map(|(date, time)| PrimitiveDateTime::new(date, time.unwrap_or(start.time())))
+            .collect();
+        Ok(Self {
+            start,
+            rule,
+            exdates,
+        })
+    }
+}
+
+/// `20250805T083000`
+pub(crate) fn basic_datetime(date_time: &PrimitiveDateTime) -> String {
+    format!(
+        "{:04}{:02}{:02}T{:02}{:02}{:02}",
+        date_time.year(),
+        date_time.month() as u8,
+        date_time.day(),
+        date_time.hour(),
+        date_time.minute(),
+        date_time.second()
+    )
+}
+
+/// `20250805`, `20250805T083000` or UTC `20250805T013000Z` converted to default offset
+pub(crate) fn parse_basic(text: &str, position: usize) -> Result<(Date, Option<Time>), ParseError> {
+    let invalid = ParseError::InvalidRule { position };
+    let (text, utc) = match text.strip_suffix(['Z', 'z']) {
+        Some(text) => (text, true),
+        None => (text, false),
+    };
+    match text.split_once(['T', 't']) {
+        None if !utc => Date::parse(text, format_description!("[year][month][day]"))
+            .map(|date| (date, None))
+            .map_err(|_| invalid),
+        Some((date, time)) => {
+            let date = Date::parse(date, format_description!("[year][month][day]"))
+                .map_err(|_| invalid)?;
+            let time = Time::parse(time, format_description!("[hour][minute][second]"))
+                .map_err(|_| invalid)?;
+            let date_time = if utc {
+                let local = PrimitiveDateTime::new(date, time)
+                    .assume_utc()
+                    .to_offset(default_offset());
+                PrimitiveDateTime::new(local.date(), local.time())
+            } else {
+                PrimitiveDateTime::new(date, time)
+            };
+            Ok((date_time.date(), Some(date_time.time())))
+        }
+        None => Err(invalid),
+    }
+}
+
+fn parse_list<T>(
+    text: &str,
+    position: usize,
+    parse: fn(&str, usize) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    let mut position = position;
+    text.split(',')
+        .map(|item| {
+            let result = parse(item, position);
+            position += item.len() + 1;
+            result
+        })
+        .collect()
+}
+
+fn parse_count(text: &str, position: usize) -> Result<u32, ParseError> {
+    text.parse::<u32>()
+        .ok()
+        .filter(|count| *count > 0)
+        .ok_or(ParseError::InvalidNumber { position })
+}
+
+fn parse_by_day(text: &str, position: usize) -> Result<ByDay, ParseError> {
+    let split = text.len().saturating_sub(2);
+    let (ordinal, code) = match (text.get(..split), text.get(split..)) {
+        (Some(ordinal), Some(code)) => (ordinal, code),
+        _ => return Err(ParseError::UnknownWeekday { position }),
+    };
+    let weekday = WEEKDAY_CODES
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(code))
+        .map(|(_, weekday)| *weekday)
+        .ok_or(ParseError::UnknownWeekday {
+            position: position + split,
+        })?;
+    if ordinal.is_empty() {
+        return Ok(ByDay::every(weekday));
+    }
+    ordinal
+        .parse::<i8>()
+        .ok()
+        .filter(|ordinal| (1..=5).contains(&ordinal.unsigned_abs()))
+        .map(|ordinal| ByDay::nth(ordinal, weekday))
+        .ok_or(ParseError::InvalidNumber { position })
+}
+
+fn parse_month_day(text: &str, position: usize) -> Result<i8, ParseError> {
+    text.parse::<i8>()
+        .ok()
+        .filter(|day| (1..=31).contains(&day.unsigned_abs()))
+        .ok_or(ParseError::InvalidNumber { position })
+}
+
+/// `a`, `aและb`, `a b และc`, space after number `วันที่ 1 และวันที่ 15`
+fn join_th(items: Vec<String>) -> String {
+    match items.split_last() {
+        None => String::new(),
+        Some((last, [])) => last.clone(),
+        Some((last, [first])) if !ends_with_digit(first) => [first.as_str(), "และ", last].concat(),
+        Some((last, rest)) => [rest.join(" "), " และ".into(), last.clone()].concat(),
+    }
+}
+
+fn ends_with_digit(text: &str) -> bool {
+    text.ends_with(|c: char| c.is_ascii_digit())
+}
+
+/// `วันอังคารที่ 2 ของเดือน`, `วันจันทร์ถึงวันศุกร์`, `None` without `BYDAY` and `BYMONTHDAY`
+fn days_th(rule: &RecurrenceRule) -> Option<String> {
+    let weekdays = rule
+        .by_day
+        .iter()
+        .map(|by_day| by_day.weekday)
+        .collect::<Vec<_>>();
+    let by_day = if rule.by_day.iter().all(|by_day| by_day.ordinal.is_none())
+        && weekdays.len() == WORKING_DAYS.len()
+        && WORKING_DAYS
+            .iter()
+            .all(|weekday| weekdays.contains(weekday))
+    {
+        String::from("วันจันทร์ถึงวันศุกร์")
+    } else {
+        join_th(rule.by_day.iter().map(ByDay::th).collect())
+    };
+    let by_month_day = join_th(
+        rule.by_month_day
+            .iter()
+            .map(|day| match *day {
+                -1 => String::from("วันสุดท้าย"),
+                day if day < 0 => format!("วันที่ {} จากท้าย", -day),
+                day => format!("วันที่ {day}"),
+            })
+            .collect(),
+    );
+    let in_month =
+        !rule.by_month_day.is_empty() || rule.by_day.iter().any(|by_day| by_day.ordinal.is_some());
+    let days = match (by_day.is_empty(), by_month_day.is_empty()) {
+        (true, true) => return None,
+        (false, true) => by_day,
+        (true, false) => by_month_day,
+        (false, false) => format!("{by_day} ที่เป็น{by_month_day}"),
+    };
+    if !in_month {
+        Some(days)
+    } else if ends_with_digit(&days) {
+        Some([days, " ของเดือน".into()].concat())
+    } else {
+        Some([days, "ของเดือน".into()].concat())
+    }
+}
+
+/// describe the rule in Thai
+/// - `ทุกวัน`, `ทุก 2 สัปดาห์ วันจันทร์และวันพุธ`
+/// - `ทุกวันอังคารที่ 2 ของเดือน`, `ทุกวันศุกร์สุดท้ายของเดือน`
+/// - `ทุกวันจันทร์ถึงวันศุกร์ จนถึง 31 ธ.ค.2568`, `ทุกวันที่ 15 ของเดือน รวม 6 ครั้ง`
+pub fn recurrence_rule_th(rule: &RecurrenceRule) -> String {
+    let mut text = describe_th(rule);
+    append_limit_th(&mut text, rule);
+    text
+}
+
+/// describe the rule with time and cancelled dates in Thai<br>
+/// `ทุกวันจันทร์ถึงวันศุกร์ เวลา 08:30 น. ยกเว้น 12 ส.ค.2568`
+pub fn recurrence_th(recurrence: &Recurrence) -> String {
+    let mut text = describe_th(&recurrence.rule);
+    text.push_str(" เวลา ");
+    text.push_str(&time_hm(&recurrence.start.time()));
+    append_limit_th(&mut text, &recurrence.rule);
+    if !recurrence.exdates.is_empty() {
+        let dates = recurrence
+            .exdates
+            .iter()
+            .map(|date_time| date_th(&date_time.date()))
+            .collect::<Vec<_>>();
+        text.push_str(" ยกเว้น ");
+        text.push_str(&dates.join(", "));
+    }
+    text
+}
+
+fn describe_th(rule: &RecurrenceRule) -> String {
+    let unit = rule.frequency.unit_th();
+    match (rule.interval.max(1), days_th(rule)) {
+        (1, Some(days)) => ["ทุก", &days].concat(),
+        (1, None) => ["ทุก", unit].concat(),
+        (n, Some(days)) => format!("ทุก {n} {unit} {days}"),
+        (n, None) => format!("ทุก {n} {unit}"),
+    }
+}
+
+fn append_limit_th(text: &mut String, rule: &RecurrenceRule) {
+    if let Some(count) = rule.count {
+        text.push_str(&format!(" รวม {count} ครั้ง"));
+    }
+    if let Some(until) = rule.until {
+        text.push_str(" จนถึง ");
+        text.push_str(&date_th(&until.date()));
+    }
+}
+
+#[cfg(test)]
+#[rustfmt::skip]
+pub mod tests {
+
+    use super::*;
+    use time::macros::{date, datetime};
+
+    #[test]
+    fn test_parse_rule() {
+        let rule: RecurrenceRule = "RRULE:FREQ=MONTHLY;BYDAY=2TU".parse().unwrap();
+        assert_eq!(rule, RecurrenceRule { by_day: vec![ByDay::nth(2, Weekday::Tuesday)], ..RecurrenceRule::new(Frequency::Monthly) });
+        assert_eq!(rule.to_string(), "FREQ=MONTHLY;BYDAY=2TU");
+        let text = "FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,WE;COUNT=10";
+        assert_eq!(text.parse::<RecurrenceRule>().unwrap().to_string(), text);
+        let text = "FREQ=MONTHLY;BYDAY=-1FR;BYMONTHDAY=-7,-6,-5,-4,-3,-2,-1;UNTIL=20251231T235959";
+        assert_eq!(text.parse::<RecurrenceRule>().unwrap().to_string(), text);
+        // case insensitive, date only UNTIL is the end of day
+        let rule: RecurrenceRule = "freq=daily;until=20251231".parse().unwrap();
+        assert_eq!(rule.until, Some(datetime!(2025-12-31 23:59:59)));
+        // errors
+        assert_eq!("".parse::<RecurrenceRule>(), Err(ParseError::Empty));
+        assert_eq!("FREQ=HOURLY".parse::<RecurrenceRule>(), Err(ParseError::InvalidRule { position: 5 }));
+        assert_eq!("FREQ=YEARLY;BYMONTH=8".parse::<RecurrenceRule>(), Err(ParseError::InvalidRule { position: 12 }));
+        assert_eq!("FREQ=MONTHLY;BYDAY=2TU,XX".parse::<RecurrenceRule>(), Err(ParseError::UnknownWeekday { position: 23 }));
+        assert_eq!("FREQ=MONTHLY;BYMONTHDAY=32".parse::<RecurrenceRule>(), Err(ParseError::InvalidNumber { position: 24 }));
+        // `i8::MIN` has no absolute value
+        assert_eq!("FREQ=MONTHLY;BYMONTHDAY=-128".parse::<RecurrenceRule>(), Err(ParseError::InvalidNumber { position: 24 }));
+        assert_eq!("FREQ=MONTHLY;BYDAY=-128MO".parse::<RecurrenceRule>(), Err(ParseError::InvalidNumber { position: 19 }));
+        assert_eq!("FREQ=WEEKLY;BYDAY=2TU".parse::<RecurrenceRule>(), Err(ParseError::InvalidRule { position: 12 }));
+        assert_eq!("BYDAY=TU".parse::<RecurrenceRule>(), Err(ParseError::InvalidRule { position: 0 }));
+        assert_eq!("FREQ=DAILY;COUNT=3;UNTIL=20251231".parse::<RecurrenceRule>(), Err(ParseError::InvalidRule { position: 0 }));
+    }
+
+    #[test]
+    fn test_parse_recurrence() {
+        let text = "DTSTART:20250805T083000\r\nRRULE:FREQ=WEEKLY;BYDAY=MO,TU,WE,TH,FR\r\nEXDATE:20250812T083000";
+        let recurrence: Recurrence = text.parse().unwrap();
+        assert_eq!(recurrence.start, datetime!(2025-08-05 08:30));
+        assert_eq!(recurrence.exdates, vec![datetime!(2025-08-12 08:30)]);
+        assert_eq!(recurrence.to_string(), text);
+        // date only EXDATE, UTC DTSTART, TZID is ignored
+        let recurrence: Recurrence = "DTSTART;TZID=Asia/Bangkok:20250805T013000Z\nRRULE:FREQ=DAILY\nEXDATE;VALUE=DATE:20250806".parse().unwrap();
+        assert_eq!(recurrence.start, datetime!(2025-08-05 08:30));
+        assert_eq!(recurrence.exdates, vec![datetime!(2025-08-06 08:30)]);
+        assert_eq!("RRULE:FREQ=DAILY".parse::<Recurrence>(), Err(ParseError::InvalidRule { position: 0 }));
+        assert_eq!("DTSTART:20250805\nRRULE:FREQ=DAILY;COUNT=0".parse::<Recurrence>(), Err(ParseError::InvalidNumber { position: 40 }));
+    }
+
+    #[test]
+    fn test_occurrences() {
+        let august = DateRange::new(date!(2025-08-01), date!(2025-08-31)).unwrap();
+        let autumn = DateRange::new(date!(2025-08-01), date!(2025-10-31)).unwrap();
+        // every 2nd Tuesday
+        let recurrence: Recurrence = "DTSTART:20250101T090000\nRRULE:FREQ=MONTHLY;BYDAY=2TU".parse().unwrap();
+        assert_eq!(recurrence.occurrences(&autumn), vec![datetime!(2025-08-12 09:00), datetime!(2025-09-09 09:00), datetime!(2025-10-14 09:00)]);
+        // monthly on the last Friday, 3 times
+        let recurrence: Recurrence = "DTSTART:20250801T130000\nRRULE:FREQ=MONTHLY;BYDAY=-1FR;COUNT=3".parse().unwrap();
+        assert_eq!(recurrence.iter().collect::<Vec<_>>(), vec![datetime!(2025-08-29 13:00), datetime!(2025-09-26 13:00), datetime!(2025-10-31 13:00)]);
+        // every weekday at 08:30 except วันแม่
+        let mut recurrence = Recurrence::new(datetime!(2025-08-06 08:30), RecurrenceRule {
+            by_day: WORKING_DAYS.into_iter().map(ByDay::every).collect(),
+            ..RecurrenceRule::new(Frequency::Daily)
+        });
+        recurrence.exdates.push(datetime!(2025-08-12 08:30));
+        let week = DateRange::new(date!(2025-08-08), date!(2025-08-14)).unwrap();
+        assert_eq!(recurrence.occurrences(&week), vec![datetime!(2025-08-08 08:30), datetime!(2025-08-11 08:30), datetime!(2025-08-13 08:30), datetime!(2025-08-14 08:30)]);
+        // every 2 weeks from DTSTART's weekday
+        let recurrence: Recurrence = "DTSTART:20250806T100000\nRRULE:FREQ=WEEKLY;INTERVAL=2".parse().unwrap();
+        assert_eq!(recurrence.occurrences(&august), vec![datetime!(2025-08-06 10:00), datetime!(2025-08-20 10:00)]);
+        // 31st skips short months, UNTIL is inclusive
+        let recurrence: Recurrence = "DTSTART:20250731\nRRULE:FREQ=MONTHLY;UNTIL=20251031".parse().unwrap();
+        assert_eq!(recurrence.iter().collect::<Vec<_>>(), vec![datetime!(2025-07-31 00:00), datetime!(2025-08-31 00:00), datetime!(2025-10-31 00:00)]);
+        // yearly on 29 ก.พ.
+        let recurrence: Recurrence = "DTSTART:20240229\nRRULE:FREQ=YEARLY;COUNT=2".parse().unwrap();
+        assert_eq!(recurrence.iter().collect::<Vec<_>>(), vec![datetime!(2024-02-29 00:00), datetime!(2028-02-29 00:00)]);
+        // never matches, stops at the end of range
+        let recurrence: Recurrence = "DTSTART:20250101\nRRULE:FREQ=MONTHLY;BYDAY=5MO;BYMONTHDAY=1".parse().unwrap();
+        assert_eq!(recurrence.occurrences(&autumn), vec![]);
+        let recurrence: Recurrence = "DTSTART:20250201\nRRULE:FREQ=YEARLY;BYMONTHDAY=31".parse().unwrap();
+        assert_eq!(recurrence.occurrences(&autumn), vec![]);
+        let recurrence: Recurrence = "DTSTART:20250201\nRRULE:FREQ=YEARLY;BYMONTHDAY=31;UNTIL=20300101".parse().unwrap();
+        assert_eq!(recurrence.iter().next(), None);
+    }
+
+    #[test]
+    fn test_recurrence_th() {
+        let th = |text: &str| recurrence_rule_th(&text.parse().unwrap());
+        assert_eq!(th("FREQ=DAILY"), "ทุกวัน");
+        assert_eq!(th("FREQ=MONTHLY;BYDAY=2TU"), "ทุกวันอังคารที่ 2 ของเดือน");
+        assert_eq!(th("FREQ=MONTHLY;BYDAY=-1FR"), "ทุกวันศุกร์สุดท้ายของเดือน");
+        assert_eq!(th("FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,WE"), "ทุก 2 สัปดาห์ วันจันทร์และวันพุธ");
+        assert_eq!(th("FREQ=WEEKLY;BYDAY=MO,WE,FR"), "ทุกวันจันทร์ วันพุธ และวันศุกร์");
+        assert_eq!(th("FREQ=MONTHLY;BYMONTHDAY=1,-1"), "ทุกวันที่ 1 และวันสุดท้ายของเดือน");
+        assert_eq!(th("FREQ=MONTHLY;BYMONTHDAY=15;COUNT=6"), "ทุกวันที่ 15 ของเดือน รวม 6 ครั้ง");
+        assert_eq!(th("FREQ=YEARLY;INTERVAL=4"), "ทุก 4 ปี");
+        let recurrence: Recurrence = "DTSTART:20250806T083000\nRRULE:FREQ=DAILY;BYDAY=MO,TU,WE,TH,FR;UNTIL=20251231\nEXDATE:20250812".parse().unwrap();
+        assert_eq!(recurrence_th(&recurrence), "ทุกวันจันทร์ถึงวันศุกร์ เวลา 08:30 น. จนถึง 31 ธ.ค.2568 ยกเว้น 12 ส.ค.2568");
+    }
+}
@@ -0,0 +1,446 @@
+use time::{Date, Duration, Month, PrimitiveDateTime, Time, Weekday};
+
+use crate::{
+    add_months, clock::default_clock, date_th, datetime_th, time_from_pat, time_hm,
+    weekday_thai_full,
+};
+
+const WEEKDAYS: [Weekday; 7] = [
+    Weekday::Sunday,
+    Weekday::Monday,
+    Weekday::Tuesday,
+    Weekday::Wednesday,
+    Weekday::Thursday,
+    Weekday::Friday,
+    Weekday::Saturday,
+];
+
+/// named days, longest first
+const NAMED_DAYS: [(&str, i64); 10] = [
+    ("เมื่อวานซืน", -2),
+    ("เมื่อวานนี้", -1),
+    ("เมื่อวาน", -1),
+    ("มะรืนนี้", 2),
+    ("มะรืน", 2),
+    ("พรุ่งนี้", 1),
+    ("วันนี้", 0),
+    ("ตอนนี้", 0),
+    ("เดี๋ยวนี้", 0),
+    ("ขณะนี้", 0),
+];
+
+/// named now, the time of `reference`
+const NOW: [&str; 3] = ["ตอนนี้", "เดี๋ยวนี้", "ขณะนี้"];
+
+#[derive(Clone, Copy)]
+enum Unit {
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+/// longest first
+const UNITS: [(&str, Unit); 8] = [
+    ("ชั่วโมง", Unit::Hour),
+    ("สัปดาห์", Unit::Week),
+    ("อาทิตย์", Unit::Week),
+    ("เดือน", Unit::Month),
+    ("นาที", Unit::Minute),
+    ("ชม.", Unit::Hour),
+    ("วัน", Unit::Day),
+    ("ปี", Unit::Year),
+];
+
+const FUTURE: [&str; 5] = ["ข้างหน้า", "หลังจากนี้", "ต่อจากนี้", "ถัดไป", "หน้า"];
+const PAST: [&str; 4] = ["ที่แล้ว", "ที่ผ่านมา", "ก่อน", "แล้ว"];
+
+/// parse Thai relative phrase to time::PrimitiveDateTime from `reference`, unknown phrase will be `None`<br>
+/// - `วันนี้`, `พรุ่งนี้`, `มะรืน`, `เมื่อวาน`, `เมื่อวานซืน`
+/// - `อีก 3 วัน`, `2 สัปดาห์ที่แล้ว`, `3 ชั่วโมงก่อน`, `+3`, `-3` days
+/// - `สัปดาห์หน้า`, `เดือนที่แล้ว`, `ปีหน้า`
+/// - `ต้นเดือน`, `สิ้นเดือนหน้า`, `สิ้นปี`
+/// - `ศุกร์นี้` on or after today, `วันจันทร์หน้า` in next week, `อังคารที่แล้ว` in last week, week starts on Sunday
+/// - optional time at the end, `พรุ่งนี้ 14:00`
+pub fn datetime_from_relative(
+    text: &str,
+    reference: &PrimitiveDateTime,
+) -> Option<PrimitiveDateTime> {
+    let text = text.trim();
+    if let Some((phrase, time)) = text.rsplit_once(' ')
+        && time.contains([':', '.'])
+        && let Some(t) = time_from_pat(time)
+    {
+        return datetime_from_relative(phrase, reference).map(|dt| dt.replace_time(t));
+    }
+    let phrase = text.split_whitespace().collect::<String>();
+    if phrase.is_empty() {
+        return None;
+    }
+    named_day(&phrase)
+        .or_else(|| signed_days(&phrase))
+        .map(|days| shift(reference, Unit::Day, days))
+        .or_else(|| amount(&phrase).map(|(unit, n)| shift(reference, unit, n)))
+        .or_else(|| weekday(&phrase, reference))
+        .or_else(|| boundary(&phrase, reference))
+        .flatten()
+}
+/// same as `datetime_from_relative()`, from `clock::default_clock()`
+pub fn datetime_from_relative_now(text: &str) -> Option<PrimitiveDateTime> {
+    datetime_from_relative(text, &default_clock().now())
+}
+/// same as `datetime_from_relative()` but discard time
+pub fn date_from_relative(text: &str, reference: &PrimitiveDateTime) -> Option<Date> {
+    datetime_from_relative(text, reference).map(|dt| dt.date())
+}
+/// same as `date_from_relative()`, from `clock::default_clock()`
+pub fn date_from_relative_now(text: &str) -> Option<Date> {
+    date_from_relative(text, &default_clock().now())
+}
+/// parse Thai relative time phrase to time::Time from `reference`, date phrase will be `None`<br>
+/// time past midnight of `reference` will be `None` too, the date is not moved silently
+/// - `ตอนนี้`, `เดี๋ยวนี้`, `ขณะนี้`
+/// - `อีก 45 นาที`, `3 ชั่วโมงก่อน`, `ชั่วโมงหน้า`
+pub fn time_from_relative(text: &str, reference: &PrimitiveDateTime) -> Option<Time> {
+    let phrase = text.split_whitespace().collect::<String>();
+    if NOW.contains(&phrase.as_str()) {
+        return Some(reference.time());
+    }
+    amount(&phrase)
+        .filter(|(unit, _)| matches!(unit, Unit::Minute | Unit::Hour))
+        .and_then(|(unit, n)| shift(reference, unit, n))
+        .filter(|dt| dt.date() == reference.date())
+        .map(|dt| dt.time())
+}
+/// same as `time_from_relative()`, from `clock::default_clock()`
+pub fn time_from_relative_now(text: &str) -> Option<Time> {
+    time_from_relative(text, &default_clock().now())
+}
+
+fn named_day(phrase: &str) -> Option<i64> {
+    NAMED_DAYS
+        .iter()
+        .find(|(name, _)| *name == phrase)
+        .map(|(_, days)| *days)
+}
+
+/// `+3` or `-3`
+fn signed_days(phrase: &str) -> Option<i64> {
+    if let Some(n) = phrase.strip_prefix('+') {
+        number(n)
+            .filter(|(_, rest)| rest.is_empty())
+            .map(|(n, _)| n)
+    } else if let Some(n) = phrase.strip_prefix('-') {
+        number(n)
+            .filter(|(_, rest)| rest.is_empty())
+            .map(|(n, _)| -n)
+    } else {
+        None
+    }
+}
+
+/// `อีก 3 วัน`, `3 วันที่แล้ว`, `สัปดาห์หน้า` or `เดือนนี้`
+fn amount(phrase: &str) -> Option<(Unit, i64)> {
+    let (ahead, phrase) = match phrase.strip_prefix("อีก") {
+        Some(rest) => (true, rest),
+        None => (false, phrase),
+    };
+    let (n, rest) = match number(phrase) {
+        Some((n, rest)) => (Some(n), rest),
+        None => (None, phrase),
+    };
+    let (unit, rest) = UNITS
+        .iter()
+        .find_map(|(name, unit)| rest.strip_prefix(name).map(|r| (*unit, r)))?;
+    match (ahead, n, rest) {
+        (true, Some(n), "") => Some((unit, n)),
+        (true, Some(n), r) if FUTURE.contains(&r) => Some((unit, n)),
+        (false, Some(n), r) if FUTURE.contains(&r) => Some((unit, n)),
+        (false, Some(n), r) if PAST.contains(&r) => Some((unit, -n)),
+        (false, None, "นี้") => Some((unit, 0)),
+        (false, None, r) if FUTURE.contains(&r) => Some((unit, 1)),
+        (false, None, r) if PAST.contains(&r) => Some((unit, -1)),
+        _ => None,
+    }
+}
+
+/// `ศุกร์นี้`, `วันจันทร์หน้า` or `อังคารที่แล้ว`
+fn weekday(phrase: &str, reference: &PrimitiveDateTime) -> Option<Option<PrimitiveDateTime>> {
+    let (prefixed, phrase) = match phrase.strip_prefix("วัน") {
+        Some(rest) => (true, rest),
+        None => (false, phrase),
+    };
+    let (ww, rest) = WEEKDAYS
+        .iter()
+        .find_map(|ww| phrase.strip_prefix(weekday_thai_full(ww)).map(|r| (*ww, r)))?;
+    // `อาทิตย์หน้า` is next week, `วันอาทิตย์หน้า` is next Sunday
+    if ww == Weekday::Sunday && !prefixed && !rest.is_empty() {
+        return None;
+    }
+    let today = reference.weekday().number_days_from_sunday() as i64;
+    let target = ww.number_days_from_sunday() as i64;
+    let days = if rest.is_empty() || rest == "นี้" {
+        (target - today).rem_euclid(7)
+    } else if FUTURE.contains(&rest) {
+        7 - today + target
+    } else if PAST.contains(&rest) {
+        target - today - 7
+    } else {
+        return None;
+    };
+    Some(shift(reference, Unit::Day, days))
+}
+
+/// `ต้นเดือน`, `สิ้นเดือนหน้า`, `ต้นปีที่แล้ว` or `สิ้นปี`
+fn boundary(phrase: &str, reference: &PrimitiveDateTime) -> Option<Option<PrimitiveDateTime>> {
+    let (start, rest) = if let Some(rest) = phrase.strip_prefix("ต้น") {
+        (true, rest)
+    } else if let Some(rest) = phrase.strip_prefix("สิ้น") {
+        (false, rest)
+    } else {
+        return None;
+    };
+    let (unit, rest) = [("เดือน", Unit::Month), ("ปี", Unit::Year)]
+        .iter()
+        .find_map(|(name, unit)| rest.strip_prefix(name).map(|r| (*unit, r)))?;
+    let n = if rest.is_empty() || rest == "นี้" {
+        0
+    } else if FUTURE.contains(&rest) {
+        1
+    } else if PAST.contains(&rest) {
+        -1
+    } else {
+        return None;
+    };
+    let date = shift(reference, unit, n)?.date();
+    let date = match (unit, start) {
+        (Unit::Year, true) => Date::from_calendar_date(date.year(), Month::January, 1).ok(),
+        (Unit::Year, false) => Date::from_calendar_date(date.year(), Month::December, 31).ok(),
+        (_, true) => date.replace_day(1).ok(),
+        (_, false) => date.replace_day(date.month().length(date.year())).ok(),
+    };
+    Some(date.map(|d| PrimitiveDateTime::new(d, reference.time())))
+}
+
+fn shift(reference: &PrimitiveDateTime, unit: Unit, n: i64) -> Option<PrimitiveDateTime> {
+    match unit {
+        Unit::Minute => reference.checked_add(Duration::minutes(n)),
+        Unit::Hour => reference.checked_add(Duration::hours(n)),
+        Unit::Day => reference.checked_add(Duration::days(n)),
+        Unit::Week => reference.checked_add(Duration::weeks(n)),
+        Unit::Month => add_months(&reference.date(), n).map(|d| reference.replace_date(d)),
+        Unit::Year => n
+            .checked_mul(12)
+            .and_then(|months| add_months(&reference.date(), months))
+            .map(|d| reference.replace_date(d)),
+    }
+}
+
+/// leading number and the rest, Thai digits supported
+fn number(text: &str) -> Option<(i64, &str)> {
+    let end = text
+        .char_indices()
+        .find(|(_, c)| !matches!(c, '0'..='9' | '๐'..='๙'))
+        .map(|(i, _)| i)
+        .unwrap_or(text.len());
+    // longer number will overflow Duration
+    if end == 0 || text[..end].chars().count() > 6 {
+        return None;
+    }
+    let n = text[..end].chars().try_fold(0i64, |n, c| {
+        let digit = match c {
+            '๐'..='๙' => c as i64 - '๐' as i64,
+            _ => c as i64 - '0' as i64,
+        };
+        n.checked_mul(10)?.checked_add(digit)
+    })?;
+    Some((n, &text[end..]))
+}
+
+// ===== ===== ===== ===== //
+//  format relative phrase  //
+// ===== ===== ===== ===== //
+
+/// when relative text falls back to absolute `date_th()`, `0` is disabled<br>
+/// named days `เมื่อวานซืน`, `เมื่อวาน`, `วันนี้`, `พรุ่งนี้`, `มะรืนนี้` are always used
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RelativeThresholds {
+    /// `5 นาทีที่แล้ว` or `อีก 5 นาที` when less than this minutes
+    pub minutes: i64,
+    /// `2 ชั่วโมงที่แล้ว` or `อีก 2 ชั่วโมง` when less than this hours
+    pub hours: i64,
+    /// `3 วันที่แล้ว` or `อีก 3 วัน` up to this days
+    pub days: i64,
+}
+
+/// parse PrimitiveDateTime to Thai relative string from `reference`, ex. `5 นาทีที่แล้ว`, `อีก 2 ชั่วโมง`, `พรุ่งนี้ 05:25 น.`
+pub fn datetime_th_relative_to(
+    date_time: &PrimitiveDateTime,
+    reference: &PrimitiveDateTime,
+    thresholds: &RelativeThresholds,
+) -> String {
+    let diff = *date_time - *reference;
+    let moment = if diff.is_negative() {
+        "เมื่อสักครู่"
+    } else {
+        "อีกสักครู่"
+    };
+    if diff.whole_minutes().abs() < thresholds.minutes {
+        match diff.whole_minutes() {
+            0 => String::from(moment),
+            minutes => relative_th(minutes, "นาที"),
+        }
+    } else if diff.whole_hours().abs() < thresholds.hours {
+        // less than an hour when minutes is disabled
+        match diff.whole_hours() {
+            0 => String::from(moment),
+            hours => relative_th(hours, "ชั่วโมง"),
+        }
+    } else {
+        let days = (date_time.date() - reference.date()).whole_days();
+        relative_days_th(days, thresholds)
+            .map(|day| [day, time_hm(&date_time.time())].join(" "))
+            .unwrap_or_else(|| datetime_th(date_time))
+    }
+}
+/// parse Date to Thai relative string from `reference`, ex. `เมื่อวาน`, `3 วันที่แล้ว`, `อีก 5 วัน`
+pub fn date_th_relative_to(
+    date: &Date,
+    reference: &Date,
+    thresholds: &RelativeThresholds,
+) -> String {
+    relative_days_th((*date - *reference).whole_days(), thresholds).unwrap_or_else(|| date_th(date))
+}
+
+fn relative_days_th(days: i64, thresholds: &RelativeThresholds) -> Option<String> {
+    match days {
+        -2 => Some(String::from("เมื่อวานซืน")),
+        -1 => Some(String::from("เมื่อวาน")),
+        0 => Some(String::from("วันนี้")),
+        1 => Some(String::from("พรุ่งนี้")),
+        2 => Some(String::from("มะรืนนี้")),
+        _ if days.abs() <= thresholds.days => Some(relative_th(days, "วัน")),
+        _ => None,
+    }
+}
+
+/// `3 วันที่แล้ว` or `อีก 3 วัน`
+fn relative_th(n: i64, unit: &str) -> String {
+    if n < 0 {
+        format!("{} {}ที่แล้ว", -n, unit)
+    } else {
+        format!("อีก {} {}", n, unit)
+    }
+}
+
+#[cfg(test)]
+#[rustfmt::skip]
+pub mod tests {
+
+    use super::*;
+    use time::macros::{date, datetime, time};
+
+    // Wednesday
+    const REFERENCE: PrimitiveDateTime = datetime!(2025-01-29 10:30);
+
+    #[test]
+    fn test_named_day() {
+        assert_eq!(datetime_from_relative("วันนี้", &REFERENCE), Some(REFERENCE));
+        assert_eq!(date_from_relative("พรุ่งนี้", &REFERENCE), Some(date!(2025-01-30)));
+        assert_eq!(date_from_relative("มะรืนนี้", &REFERENCE), Some(date!(2025-01-31)));
+        assert_eq!(date_from_relative("เมื่อวาน", &REFERENCE), Some(date!(2025-01-28)));
+        assert_eq!(date_from_relative("เมื่อวานซืน", &REFERENCE), Some(date!(2025-01-27)));
+        assert_eq!(datetime_from_relative("พรุ่งนี้ 14:00", &REFERENCE), Some(datetime!(2025-01-30 14:00)));
+        assert_eq!(date_from_relative("+3", &REFERENCE), Some(date!(2025-02-01)));
+        assert_eq!(date_from_relative("-๓", &REFERENCE), Some(date!(2025-01-26)));
+    }
+
+    #[test]
+    fn test_amount() {
+        assert_eq!(date_from_relative("อีก 3 วัน", &REFERENCE), Some(date!(2025-02-01)));
+        assert_eq!(date_from_relative("อีก 2 สัปดาห์", &REFERENCE), Some(date!(2025-02-12)));
+        assert_eq!(date_from_relative("2 สัปดาห์ที่แล้ว", &REFERENCE), Some(date!(2025-01-15)));
+        assert_eq!(datetime_from_relative("3 ชั่วโมงก่อน", &REFERENCE), Some(datetime!(2025-01-29 07:30)));
+        assert_eq!(datetime_from_relative("อีก 45 นาที", &REFERENCE), Some(datetime!(2025-01-29 11:15)));
+        assert_eq!(date_from_relative("สัปดาห์หน้า", &REFERENCE), Some(date!(2025-02-05)));
+        assert_eq!(date_from_relative("อาทิตย์หน้า", &REFERENCE), Some(date!(2025-02-05)));
+        // clamp to end of month
+        assert_eq!(date_from_relative("อีก 1 เดือน", &REFERENCE), Some(date!(2025-02-28)));
+        assert_eq!(date_from_relative("เดือนที่แล้ว", &REFERENCE), Some(date!(2024-12-29)));
+        assert_eq!(date_from_relative("ปีหน้า", &REFERENCE), Some(date!(2026-01-29)));
+    }
+
+    #[test]
+    fn test_time_from_relative() {
+        assert_eq!(time_from_relative("ตอนนี้", &REFERENCE), Some(REFERENCE.time()));
+        assert_eq!(time_from_relative("อีก 45 นาที", &REFERENCE), Some(time!(11:15)));
+        assert_eq!(time_from_relative("3 ชั่วโมงก่อน", &REFERENCE), Some(time!(07:30)));
+        assert_eq!(time_from_relative("อีก 13 ชั่วโมง", &REFERENCE), Some(time!(23:30)));
+        // past midnight
+        assert_eq!(time_from_relative("อีก 14 ชั่วโมง", &REFERENCE), None);
+        assert_eq!(time_from_relative("11 ชั่วโมงก่อน", &REFERENCE), None);
+        // date phrases
+        assert_eq!(time_from_relative("พรุ่งนี้", &REFERENCE), None);
+        assert_eq!(time_from_relative("วันนี้", &REFERENCE), None);
+        assert_eq!(time_from_relative("+3", &REFERENCE), None);
+        assert_eq!(time_from_relative("อีก 3 วัน", &REFERENCE), None);
+        assert_eq!(time_from_relative("พรุ่งนี้ 14:00", &REFERENCE), None);
+        assert_eq!(time_from_relative("ศุกร์นี้", &REFERENCE), None);
+    }
+
+    #[test]
+    fn test_weekday() {
+        assert_eq!(date_from_relative("พุธนี้", &REFERENCE), Some(date!(2025-01-29)));
+        assert_eq!(date_from_relative("ศุกร์นี้", &REFERENCE), Some(date!(2025-01-31)));
+        assert_eq!(date_from_relative("วันจันทร์", &REFERENCE), Some(date!(2025-02-03)));
+        assert_eq!(date_from_relative("วันจันทร์หน้า", &REFERENCE), Some(date!(2025-02-03)));
+        assert_eq!(date_from_relative("วันอาทิตย์หน้า", &REFERENCE), Some(date!(2025-02-02)));
+        assert_eq!(date_from_relative("อังคารที่แล้ว", &REFERENCE), Some(date!(2025-01-21)));
+    }
+
+    #[test]
+    fn test_boundary() {
+        assert_eq!(date_from_relative("ต้นเดือน", &REFERENCE), Some(date!(2025-01-01)));
+        assert_eq!(date_from_relative("สิ้นเดือน", &REFERENCE), Some(date!(2025-01-31)));
+        assert_eq!(date_from_relative("สิ้นเดือนหน้า", &REFERENCE), Some(date!(2025-02-28)));
+        assert_eq!(date_from_relative("สิ้นปี", &REFERENCE), Some(date!(2025-12-31)));
+        assert_eq!(date_from_relative("ต้นปีที่แล้ว", &REFERENCE), Some(date!(2024-01-01)));
+    }
+
+    #[test]
+    fn test_unknown() {
+        assert_eq!(datetime_from_relative("", &REFERENCE), None);
+        assert_eq!(datetime_from_relative("30/01/2568", &REFERENCE), None);
+        assert_eq!(datetime_from_relative("1430", &REFERENCE), None);
+        assert_eq!(datetime_from_relative("อีก วัน", &REFERENCE), None);
+        assert_eq!(datetime_from_relative("อีก 9999999999 สัปดาห์", &REFERENCE), None);
+    }
+
+    #[test]
+    fn test_datetime_th_relative_to() {
+        let thresholds = RelativeThresholds { minutes: 60, hours: 6, days: 7 };
+        assert_eq!(datetime_th_relative_to(&datetime!(2025-01-29 10:29:30), &REFERENCE, &thresholds), String::from("เมื่อสักครู่"));
+        assert_eq!(datetime_th_relative_to(&datetime!(2025-01-29 10:25), &REFERENCE, &thresholds), String::from("5 นาทีที่แล้ว"));
+        assert_eq!(datetime_th_relative_to(&datetime!(2025-01-29 12:45), &REFERENCE, &thresholds), String::from("อีก 2 ชั่วโมง"));
+        assert_eq!(datetime_th_relative_to(&datetime!(2025-01-29 20:00), &REFERENCE, &thresholds), String::from("วันนี้ 20:00 น."));
+        assert_eq!(datetime_th_relative_to(&datetime!(2025-01-31 05:25), &REFERENCE, &thresholds), String::from("มะรืนนี้ 05:25 น."));
+        assert_eq!(datetime_th_relative_to(&datetime!(2025-01-26 05:25), &REFERENCE, &thresholds), String::from("3 วันที่แล้ว 05:25 น."));
+        assert_eq!(datetime_th_relative_to(&datetime!(2025-01-26 05:25), &REFERENCE, &RelativeThresholds::default()), String::from("26 ม.ค.2568 05:25 น."));
+        // hours without minutes
+        let thresholds = RelativeThresholds { minutes: 0, hours: 6, days: 0 };
+        assert_eq!(datetime_th_relative_to(&datetime!(2025-01-29 10:25), &REFERENCE, &thresholds), String::from("เมื่อสักครู่"));
+        assert_eq!(datetime_th_relative_to(&datetime!(2025-01-29 08:25), &REFERENCE, &thresholds), String::from("2 ชั่วโมงที่แล้ว"));
+    }
+
+    #[test]
+    fn test_date_th_relative_to() {
+        let thresholds = RelativeThresholds { days: 7, ..Default::default() };
+        assert_eq!(date_th_relative_to(&date!(2025-01-28), &REFERENCE.date(), &thresholds), String::from("เมื่อวาน"));
+        assert_eq!(date_th_relative_to(&date!(2025-01-27), &REFERENCE.date(), &thresholds), String::from("เมื่อวานซืน"));
+        assert_eq!(date_th_relative_to(&date!(2025-01-30), &REFERENCE.date(), &thresholds), String::from("พรุ่งนี้"));
+        assert_eq!(date_th_relative_to(&date!(2025-02-03), &REFERENCE.date(), &thresholds), String::from("อีก 5 วัน"));
+        assert_eq!(date_th_relative_to(&date!(2025-02-06), &REFERENCE.date(), &thresholds), String::from("6 ก.พ.2568"));
+    }
+}
@@ -0,0 +1,203 @@
+//! `#[serde(with = "...")]` modules for `time` values in picker and Thai formats
+//!
+//! ```ignore
+//! #[derive(Serialize, Deserialize)]
+//! struct Visit {
+//!     #[serde(with = "picker_util::serde_fmt::js_datetime")]
+//!     arrived: PrimitiveDateTime,
+//!     #[serde(with = "picker_util::serde_fmt::pat_date_opt", default)]
+//!     birth: Option<Date>,
+//! }
+//! ```
+//! `_opt` modules write `None` as `null` and read `null` or `""` as `None`
+
+use serde::{Deserialize, Deserializer, Serializer, de::Error};
+use time::{Date, PrimitiveDateTime, Time};
+
+use crate::{
+    JsTime, date_pat, date_th, datetime_from_ts, datetime_pat, datetime_th, datetime_ts,
+    parse_th::{try_date_from_th, try_datetime_from_th},
+    try_date_8601, try_date_from_pat, try_datetime_8601, try_datetime_from_pat, try_time_8601,
+};
+
+macro_rules! string_format {
+    ($(#[$doc:meta])* $name:ident, $opt:ident, $ty:ty, $format:expr, $parse:expr) => {
+        $(#[$doc])*
+        pub mod $name {
+            use super::*;
+
+            pub fn serialize<S: Serializer>(value: &$ty, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(&$format(value))
+            }
+
+            pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<$ty, D::Error> {
+                let text = String::deserialize(deserializer)?;
+                $parse(&text).map_err(D::Error::custom)
+            }
+        }
+
+        $(#[$doc])*
+        pub mod $opt {
+            use super::*;
+
+            pub fn serialize<S: Serializer>(value: &Option<$ty>, serializer: S) -> Result<S::Ok, S::Error> {
+                match value {
+                    Some(value) => serializer.serialize_str(&$format(value)),
+                    None => serializer.serialize_none(),
+                }
+            }
+
+            pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<$ty>, D::Error> {
+                match Option::<String>::deserialize(deserializer)? {
+                    Some(text) if !text.trim().is_empty() => $parse(&text).map(Some).map_err(D::Error::custom),
+                    _ => Ok(None),
+                }
+            }
+        }
+    };
+}
+
+string_format!(
+    /// `2024-01-30T12:34` from `JsTime::js_string()`
+    js_datetime, js_datetime_opt, PrimitiveDateTime,
+    |value: &PrimitiveDateTime| value.js_string(),
+    try_datetime_8601
+);
+
+string_format!(
+    /// `2024-01-30`
+    js_date, js_date_opt, Date,
+    |value: &Date| value.to_string(),
+    try_date_8601
+);
+
+string_format!(
+    /// `12:34` from `JsTime::js_string()`
+    js_time, js_time_opt, Time,
+    |value: &Time| value.js_string(),
+    try_time_8601
+);
+
+string_format!(
+    /// Buddhist era `30/01/2567` from `date_pat()`
+    pat_date, pat_date_opt, Date,
+    date_pat,
+    try_date_from_pat
+);
+
+string_format!(
+    /// Buddhist era `30/01/2567 12:34` from `datetime_pat()`
+    pat_datetime, pat_datetime_opt, PrimitiveDateTime,
+    datetime_pat,
+    try_datetime_from_pat
+);
+
+string_format!(
+    /// Thai `30 ม.ค.2567` from `date_th()`
+    th_date, th_date_opt, Date,
+    date_th,
+    try_date_from_th
+);
+
+string_format!(
+    /// Thai `30 ม.ค.2567 12:34 น.` from `datetime_th()`
+    th_datetime, th_datetime_opt, PrimitiveDateTime,
+    datetime_th,
+    try_datetime_from_th
+);
+
+/// UNIX timestamp milliseconds from `datetime_ts()` at default offset
+pub mod ts_millis {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(
+        value: &PrimitiveDateTime,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i64(datetime_ts(value))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<PrimitiveDateTime, D::Error> {
+        let millis = i64::deserialize(deserializer)?;
+        datetime_from_ts(millis).ok_or_else(|| D::Error::custom("timestamp out of range"))
+    }
+}
+
+/// UNIX timestamp milliseconds from `datetime_ts()` at default offset
+pub mod ts_millis_opt {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(
+        value: &Option<PrimitiveDateTime>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(value) => serializer.serialize_i64(datetime_ts(value)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<PrimitiveDateTime>, D::Error> {
+        match Option::<i64>::deserialize(deserializer)? {
+            Some(millis) => datetime_from_ts(millis)
+                .map(Some)
+                .ok_or_else(|| D::Error::custom("timestamp out of range")),
+            None => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+#[rustfmt::skip]
+pub mod tests {
+
+    use serde_derive::{Deserialize, Serialize};
+    use time::{Date, PrimitiveDateTime, Time, macros::{date, datetime, time}};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Visit {
+        #[serde(with = "super::js_datetime")]
+        arrived: PrimitiveDateTime,
+        #[serde(with = "super::js_time_opt")]
+        left: Option<Time>,
+        #[serde(with = "super::pat_date")]
+        birth: Date,
+        #[serde(with = "super::pat_datetime_opt")]
+        operated: Option<PrimitiveDateTime>,
+        #[serde(with = "super::th_date")]
+        admitted: Date,
+        #[serde(with = "super::ts_millis")]
+        updated: PrimitiveDateTime,
+        #[serde(with = "super::ts_millis_opt", default)]
+        deleted: Option<PrimitiveDateTime>,
+    }
+
+    #[test]
+    fn test_serde_fmt() {
+        let visit = Visit {
+            arrived: datetime!(2024-01-30 12:34),
+            left: None,
+            birth: date!(1978-08-24),
+            operated: Some(datetime!(2024-01-30 13:00)),
+            admitted: date!(2024-01-30),
+            updated: datetime!(2024-01-30 01:23:45),
+            deleted: None,
+        };
+        let json = r#"{"arrived":"2024-01-30T12:34","left":null,"birth":"24/08/2521","operated":"30/01/2567 13:00","admitted":"30 ม.ค.2567","updated":1706552625000,"deleted":null}"#;
+        assert_eq!(serde_json::to_string(&visit).unwrap(), json);
+        assert_eq!(serde_json::from_str::<Visit>(json).unwrap(), visit);
+        // empty string is None, missing field with default
+        let json = r#"{"arrived":"2024-01-30T12:34","left":"","birth":"24/08/2521","operated":null,"admitted":"30 ม.ค.2567","updated":1706552625000}"#;
+        let visit = serde_json::from_str::<Visit>(json).unwrap();
+        assert_eq!((visit.left, visit.operated, visit.deleted), (None, None, None));
+        let json = r#"{"arrived":"2024-01-30T12:34","left":"13:00","birth":"24/08/2521","operated":null,"admitted":"30 ม.ค.2567","updated":1706552625000}"#;
+        assert_eq!(serde_json::from_str::<Visit>(json).unwrap().left, Some(time!(13:00)));
+        // error message from ParseError
+        let json = r#"{"arrived":"2024-01-30T12:34","left":null,"birth":"31/02/2521","operated":null,"admitted":"30 ม.ค.2567","updated":1706552625000}"#;
+        assert!(serde_json::from_str::<Visit>(json).unwrap_err().to_string().starts_with("day 31 out of range"));
+    }
+}
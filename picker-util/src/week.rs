@@ -0,0 +1,111 @@
+use time::{Date, Duration, Month, Weekday};
+
+use crate::range::{DateRange, date_range_th};
+
+/// ISO-8601 week-year in Common era and week 1-53, week starts on Monday<br>
+/// `2025-12-29` is week 1 of 2026
+pub fn iso_week(date: &Date) -> (i32, u8) {
+    let (year, week, _) = date.to_iso_week_date();
+    (year, week)
+}
+
+/// Monday and Sunday of ISO-8601 week, `None` if the week does not exist
+pub fn iso_week_range(year: i32, week: u8) -> Option<(Date, Date)> {
+    let start = Date::from_iso_week_date(year, week, Weekday::Monday).ok()?;
+    Some((start, start.checked_add(Duration::days(6))?))
+}
+
+/// week 1-54 of Common era year with Sunday first, as rows of `DatePicker`<br>
+/// week 1 contains `1 ม.ค.`, week 2 starts on the next Sunday
+pub fn sunday_week(date: &Date) -> u8 {
+    let offset = date
+        .replace_ordinal(1)
+        .map_or(0, |jan1| jan1.weekday().number_days_from_sunday() as u16);
+    ((date.ordinal() + offset - 1) / 7 + 1) as u8
+}
+
+/// Sunday and Saturday of `sunday_week()`, week 1 may start in previous year,
+/// `None` if the week does not exist
+pub fn sunday_week_range(year: i32, week: u8) -> Option<(Date, Date)> {
+    let jan1 = Date::from_calendar_date(year, Month::January, 1).ok()?;
+    let last = Date::from_calendar_date(year, Month::December, 31).ok()?;
+    if week == 0 || week > sunday_week(&last) {
+        return None;
+    }
+    let start =
+        week_start(&jan1, Weekday::Sunday)?.checked_add(Duration::weeks(week as i64 - 1))?;
+    Some((start, start.checked_add(Duration::days(6))?))
+}
+
+/// first date of the week containing `date`, `first` is `Weekday::Monday` for ISO-8601
+pub fn week_start(date: &Date, first: Weekday) -> Option<Date> {
+    let days = (date.weekday().number_days_from_monday() + 7 - first.number_days_from_monday()) % 7;
+    date.checked_sub(Duration::days(days as i64))
+}
+
+/// last date of the week containing `date`
+pub fn week_end(date: &Date, first: Weekday) -> Option<Date> {
+    week_start(date, first)?.checked_add(Duration::days(6))
+}
+
+/// `สัปดาห์ที่ 32 ปี 2568`, `year` is Common era
+pub fn week_th(year: i32, week: u8) -> String {
+    format!("สัปดาห์ที่ {} ปี {}", week, year + 543)
+}
+
+/// ISO-8601 week of date, `สัปดาห์ที่ 32 ปี 2568 (4–10 ส.ค.2568)`
+pub fn iso_week_th(date: &Date) -> String {
+    let (year, week) = iso_week(date);
+    let days = iso_week_range(year, week)
+        .and_then(|(start, end)| DateRange::new(start, end))
+        .map(|range| format!(" ({})", date_range_th(&range)))
+        .unwrap_or_default();
+    [week_th(year, week), days].concat()
+}
+
+#[cfg(test)]
+#[rustfmt::skip]
+pub mod tests {
+
+    use super::*;
+    use time::macros::date;
+
+    #[test]
+    fn test_iso_week() {
+        assert_eq!(iso_week(&date!(2025-08-04)), (2025, 32));
+        assert_eq!(iso_week(&date!(2025-12-29)), (2026, 1));
+        assert_eq!(iso_week(&date!(2021-01-03)), (2020, 53));
+        assert_eq!(iso_week_range(2025, 32), Some((date!(2025-08-04), date!(2025-08-10))));
+        assert_eq!(iso_week_range(2026, 1), Some((date!(2025-12-29), date!(2026-01-04))));
+        assert_eq!(iso_week_range(2025, 53), None);
+    }
+
+    #[test]
+    fn test_sunday_week() {
+        // 1 ม.ค. 2568 is Wednesday
+        assert_eq!(sunday_week(&date!(2025-01-01)), 1);
+        assert_eq!(sunday_week(&date!(2025-01-04)), 1);
+        assert_eq!(sunday_week(&date!(2025-01-05)), 2);
+        assert_eq!(sunday_week(&date!(2025-12-31)), 53);
+        assert_eq!(sunday_week_range(2025, 1), Some((date!(2024-12-29), date!(2025-01-04))));
+        assert_eq!(sunday_week_range(2025, 53), Some((date!(2025-12-28), date!(2026-01-03))));
+        assert_eq!(sunday_week_range(2025, 54), None);
+        // leap year starts on Saturday has 54 weeks
+        assert_eq!(sunday_week(&date!(2028-12-31)), 54);
+    }
+
+    #[test]
+    fn test_week_start() {
+        assert_eq!(week_start(&date!(2025-08-06), Weekday::Monday), Some(date!(2025-08-04)));
+        assert_eq!(week_start(&date!(2025-08-06), Weekday::Sunday), Some(date!(2025-08-03)));
+        assert_eq!(week_start(&date!(2025-08-03), Weekday::Sunday), Some(date!(2025-08-03)));
+        assert_eq!(week_end(&date!(2025-08-06), Weekday::Sunday), Some(date!(2025-08-09)));
+    }
+
+    #[test]
+    fn test_week_th() {
+        assert_eq!(week_th(2025, 32), String::from("สัปดาห์ที่ 32 ปี 2568"));
+        assert_eq!(iso_week_th(&date!(2025-08-06)), String::from("สัปดาห์ที่ 32 ปี 2568 (4–10 ส.ค.2568)"));
+        assert_eq!(iso_week_th(&date!(2025-12-30)), String::from("สัปดาห์ที่ 1 ปี 2569 (29 ธ.ค.2568–4 ม.ค.2569)"));
+    }
+}
@@ -0,0 +1,46 @@
+//! `set_default_offset()` is global, its own test binary keeps other tests at GMT +7
+
+use picker_util::{
+    clock::FixedClock,
+    datetime_ts, default_offset,
+    ical::{Calendar, Event, EventTime, write_ics},
+    now, now_at,
+    recurrence::Recurrence,
+    serde_fmt, set_default_offset,
+};
+use serde_derive::{Deserialize, Serialize};
+use time::{
+    Duration, PrimitiveDateTime, UtcOffset,
+    macros::{datetime, offset},
+};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Visit {
+    #[serde(with = "serde_fmt::ts_millis")]
+    at: PrimitiveDateTime,
+}
+
+#[test]
+#[rustfmt::skip]
+fn test_set_default_offset() {
+    assert_eq!(default_offset(), offset!(+7));
+    set_default_offset(UtcOffset::UTC);
+    assert_eq!(default_offset(), UtcOffset::UTC);
+    assert!((now() - now_at(UtcOffset::UTC)).abs() < Duration::minutes(1));
+    assert_eq!(datetime_ts(&datetime!(1970-01-01 00:00)), 0);
+    let visit = Visit { at: datetime!(1970-01-01 00:00:01) };
+    assert_eq!(serde_json::to_string(&visit).unwrap(), r#"{"at":1000}"#);
+    assert_eq!(serde_json::from_str::<Visit>(r#"{"at":1000}"#).unwrap(), visit);
+    // UTC `Z` and `Asia/Bangkok` times are read at default offset
+    let recurrence: Recurrence = "DTSTART:20250805T013000Z\nRRULE:FREQ=DAILY;UNTIL=20250806T013000Z".parse().unwrap();
+    assert_eq!(recurrence.start, datetime!(2025-08-05 01:30));
+    assert_eq!(recurrence.rule.until, Some(datetime!(2025-08-06 01:30)));
+    let calendar: Calendar = "BEGIN:VCALENDAR\nBEGIN:VEVENT\nUID:a\nDTSTART;TZID=Asia/Bangkok:20250805T083000\nEND:VEVENT\nEND:VCALENDAR".parse().unwrap();
+    assert_eq!(calendar.events[0].time, EventTime::Timed { start: datetime!(2025-08-05 01:30), end: None });
+    // and written back as `Asia/Bangkok` time
+    let calendar = Calendar::new(vec![Event::timed("a", "x", datetime!(2025-08-05 01:30), None)]);
+    let ics = write_ics(&calendar, &FixedClock::at(datetime!(2025-08-01 09:00), offset!(+7)));
+    assert!(ics.contains("\r\nDTSTART;TZID=Asia/Bangkok:20250805T083000\r\n"));
+    assert!(ics.contains("\r\nDTSTAMP:20250801T020000Z\r\n"));
+    set_default_offset(offset!(+7));
+}